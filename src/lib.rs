@@ -15,8 +15,14 @@
 //!
 //! ### Optional features:
 //! - [`serde`][]: Enable serialization/deserialization via serde.
+//! - [`chrono-tz`][]: Enable DST aware wall clock arithmetic in named timezones.
+//! - [`hifitime`][]: Enable calendar arithmetic on `hifitime::Epoch`.
+//! - [`time`][]: Enable calendar helpers for the `time` crate.
 //!
 //! [`serde`]: https://github.com/serde-rs/serde
+//! [`chrono-tz`]: https://github.com/chronotope/chrono-tz
+//! [`hifitime`]: https://github.com/nyx-space/hifitime
+//! [`time`]: https://github.com/time-rs/time
 //!
 //! ## Overview
 //!
@@ -82,12 +88,17 @@
 extern crate impl_ops;
 
 pub mod relativedelta;
-pub use crate::relativedelta::RelativeDelta;
+#[cfg(feature = "chrono-tz")]
+pub use crate::relativedelta::Disambiguation;
+pub use crate::relativedelta::{
+    range, Anchor, BuildError, ByTotalMonths, DateTimeExt, DayOverflow, DayShift, FormatError,
+    FromError, Ordinal, ParseError, RelativeDelta, RelativeDeltaFields, Unit, WeekStart,
+};
 
 #[cfg(test)]
 mod tests {
-    use crate::relativedelta::RelativeDelta;
-    use chrono::{TimeZone, Utc, Weekday};
+    use crate::relativedelta::{FormatError, ParseError, RelativeDelta, WeekStart};
+    use chrono::{DateTime, TimeZone, Utc, Weekday};
 
     #[test]
     fn test_add_self() {
@@ -286,4 +297,2946 @@ mod tests {
         let empty = RelativeDelta::default();
         assert!(empty.is_empty())
     }
+
+    #[test]
+    fn test_weekday_count_in_month() {
+        let dt = Utc.with_ymd_and_hms(2021, 1, 15, 0, 0, 0).unwrap();
+        // January 2021 holds five fridays, February 2021 only four
+        assert_eq!(
+            RelativeDelta::default().weekday_count_in_month(&dt, Weekday::Fri),
+            5
+        );
+        assert_eq!(
+            RelativeDelta::with_months(1)
+                .new()
+                .weekday_count_in_month(&dt, Weekday::Fri),
+            4
+        );
+        assert_eq!(
+            RelativeDelta::with_months(1)
+                .new()
+                .weekday_count_in_month(&dt, Weekday::Mon),
+            4
+        );
+    }
+
+    #[test]
+    fn test_keep_months() {
+        let months18 = RelativeDelta::with_months(18).keep_months().new();
+        assert_eq!(months18.months(), 18);
+        assert_eq!(months18.years(), 0);
+        assert_eq!(months18.total_months(), 18);
+        assert_ne!(months18, RelativeDelta::with_months(18).new());
+
+        // Everything else is still normalized
+        let rddt = RelativeDelta::with_months(-30)
+            .keep_months()
+            .and_hours(25)
+            .new();
+        assert_eq!(rddt.months(), -30);
+        assert_eq!(rddt.days(), 1);
+        assert_eq!(rddt.hours(), 1);
+
+        // Derived copies keep the months as well
+        let bumped = months18.bump_year(0);
+        assert_eq!((bumped.years(), bumped.months()), (0, 18));
+        assert_eq!(months18.bump_day(1).months(), 18);
+        assert_eq!(rddt.abs().months(), 30);
+        assert_eq!(months18.scale_date_only(2.0).months(), 36);
+        assert_eq!(months18.scale_time_only(2.0).months(), 18);
+
+        // Unless the months were aggregated to begin with
+        let months6 = RelativeDelta::with_months(6).new();
+        let scaled = months6.scale_date_only(3.0);
+        assert_eq!((scaled.years(), scaled.months()), (1, 6));
+    }
+
+    #[test]
+    fn test_on_weekday_of_month() {
+        let second_tuesday_of_march = RelativeDelta::on_weekday_of_month(Weekday::Tue, 2, 3).new();
+        assert_eq!(second_tuesday_of_march.month(), Some(3));
+        assert_eq!(second_tuesday_of_march.day(), Some(1));
+
+        let expected = Utc.with_ymd_and_hms(2021, 3, 9, 0, 0, 0).unwrap();
+        for (month, day) in [(1, 15), (3, 1), (3, 20), (7, 31), (12, 31)] {
+            let dt = Utc.with_ymd_and_hms(2021, month, day, 0, 0, 0).unwrap();
+            assert_eq!(dt + second_tuesday_of_march, expected);
+        }
+
+        // Negative nth counts back from the end of the month, clamped to its length
+        let last_friday_of_february = RelativeDelta::on_weekday_of_month(Weekday::Fri, -1, 2).new();
+        assert_eq!(last_friday_of_february.day(), Some(31));
+        for (year, eday) in [(2021, 26), (2024, 23), (2019, 22)] {
+            let dt = Utc.with_ymd_and_hms(year, 7, 4, 0, 0, 0).unwrap();
+            assert_eq!(
+                dt + last_friday_of_february,
+                Utc.with_ymd_and_hms(year, 2, eday, 0, 0, 0).unwrap()
+            );
+        }
+        let second_to_last_sunday_of_april =
+            RelativeDelta::on_weekday_of_month(Weekday::Sun, -2, 4).new();
+        let dt = Utc.with_ymd_and_hms(2021, 1, 1, 0, 0, 0).unwrap();
+        assert_eq!(
+            dt + second_to_last_sunday_of_april,
+            Utc.with_ymd_and_hms(2021, 4, 18, 0, 0, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_saturating_add_sub() {
+        let early = Utc.with_ymd_and_hms(-262_000, 6, 1, 0, 0, 0).unwrap();
+        let late = Utc.with_ymd_and_hms(262_000, 6, 1, 0, 0, 0).unwrap();
+        let years5000 = RelativeDelta::with_years(5000).new();
+
+        assert_eq!(
+            years5000.saturating_sub_from(&early),
+            DateTime::<Utc>::MIN_UTC
+        );
+        assert_eq!(years5000.saturating_add_to(&late), DateTime::<Utc>::MAX_UTC);
+        assert_eq!(years5000.saturating_add_to(&early), early + years5000);
+        assert_eq!(years5000.saturating_sub_from(&late), late - years5000);
+
+        let days = RelativeDelta::with_days(-1_000_000).new();
+        assert_eq!(days.saturating_add_to(&early), DateTime::<Utc>::MIN_UTC);
+        assert_eq!(days.saturating_sub_from(&late), DateTime::<Utc>::MAX_UTC);
+    }
+
+    #[test]
+    #[should_panic(expected = "Could not add")]
+    fn test_saturating_add_invalid_day_panics() {
+        use crate::DayOverflow;
+
+        let dt = Utc.with_ymd_and_hms(2021, 2, 10, 0, 0, 0).unwrap();
+        RelativeDelta::with_day(31)
+            .and_day_overflow(DayOverflow::Error)
+            .new()
+            .saturating_add_to(&dt);
+    }
+
+    #[cfg(feature = "chrono-tz")]
+    #[test]
+    #[should_panic(expected = "Could not add")]
+    fn test_saturating_add_dst_gap_panics() {
+        use chrono_tz::America::New_York;
+
+        // 02:00 never happens on 2021-03-14
+        let dt = New_York.with_ymd_and_hms(2021, 3, 10, 12, 0, 0).unwrap();
+        RelativeDelta::with_day(14)
+            .and_hour(Some(2))
+            .new()
+            .saturating_add_to(&dt);
+    }
+
+    #[test]
+    fn test_format() {
+        let rddt = RelativeDelta::with_years(1)
+            .and_months(-2)
+            .and_days(17)
+            .and_hours(5)
+            .and_minutes(30)
+            .and_seconds(15)
+            .and_nanoseconds(42)
+            .new();
+        assert_eq!(
+            rddt.format("{years}y {months}m {days}d").unwrap(),
+            "1y -2m 17d"
+        );
+        assert_eq!(
+            rddt.format("{weeks}w, {hours}:{minutes}:{seconds}.{nanoseconds} {{raw}}")
+                .unwrap(),
+            "2w, 5:30:15.42 {raw}"
+        );
+        assert_eq!(
+            rddt.format("{years} {decades}"),
+            Err(FormatError::UnknownPlaceholder("decades".to_string()))
+        );
+        assert_eq!(rddt.format("{years"), Err(FormatError::UnmatchedBrace));
+        assert_eq!(rddt.format("years}"), Err(FormatError::UnmatchedBrace));
+    }
+
+    #[cfg(feature = "chrono-tz")]
+    #[test]
+    fn test_add_in_zone() {
+        use crate::Disambiguation;
+        use chrono_tz::America::New_York;
+
+        let month = RelativeDelta::with_months(1).new();
+        // Across spring forward the wall clock time is kept
+        let dt = New_York.with_ymd_and_hms(2021, 3, 1, 12, 0, 0).unwrap();
+        assert_eq!(
+            month.add_in_zone(dt, Disambiguation::Reject),
+            Some(New_York.with_ymd_and_hms(2021, 4, 1, 12, 0, 0).unwrap())
+        );
+
+        // 01:30 happens twice on 2021-11-07
+        let dt = New_York.with_ymd_and_hms(2021, 10, 7, 1, 30, 0).unwrap();
+        let earliest = month.add_in_zone(dt, Disambiguation::Earliest).unwrap();
+        let latest = month.add_in_zone(dt, Disambiguation::Latest).unwrap();
+        assert_eq!(
+            earliest.naive_utc(),
+            Utc.with_ymd_and_hms(2021, 11, 7, 5, 30, 0)
+                .unwrap()
+                .naive_utc()
+        );
+        assert_eq!(
+            latest.naive_utc(),
+            Utc.with_ymd_and_hms(2021, 11, 7, 6, 30, 0)
+                .unwrap()
+                .naive_utc()
+        );
+        assert_eq!(month.add_in_zone(dt, Disambiguation::Reject), None);
+
+        // 02:30 never happens on 2021-03-14
+        let dt = New_York.with_ymd_and_hms(2021, 2, 14, 2, 30, 0).unwrap();
+        let earliest = month.add_in_zone(dt, Disambiguation::Earliest).unwrap();
+        let latest = month.add_in_zone(dt, Disambiguation::Latest).unwrap();
+        assert_eq!(
+            earliest,
+            New_York.with_ymd_and_hms(2021, 3, 14, 1, 30, 0).unwrap()
+        );
+        assert_eq!(
+            latest,
+            New_York.with_ymd_and_hms(2021, 3, 14, 3, 30, 0).unwrap()
+        );
+        assert_eq!(month.add_in_zone(dt, Disambiguation::Reject), None);
+    }
+
+    #[cfg(feature = "chrono-tz")]
+    #[test]
+    fn test_add_in_zone_weekday_across_dst() {
+        use crate::Disambiguation;
+        use chrono_tz::America::New_York;
+
+        // From friday before spring forward on 2021-03-14 to the next monday
+        let dt = New_York.with_ymd_and_hms(2021, 3, 12, 12, 0, 0).unwrap();
+        let monday = RelativeDelta::with_weekday(Weekday::Mon, 1).new();
+        assert_eq!(
+            monday.add_in_zone(dt, Disambiguation::Reject),
+            Some(New_York.with_ymd_and_hms(2021, 3, 15, 12, 0, 0).unwrap())
+        );
+        // The operator adds exact days, moving the wall clock
+        assert_eq!(
+            dt + monday,
+            New_York.with_ymd_and_hms(2021, 3, 15, 13, 0, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_week_of_month() {
+        // August 2021 starts on a sunday
+        let monday_start = RelativeDelta::default();
+        let sunday_start = RelativeDelta::with_week_start(WeekStart(Weekday::Sun)).new();
+        assert_eq!(monday_start.week_start(), WeekStart(Weekday::Mon));
+        assert_eq!(sunday_start.week_start(), WeekStart(Weekday::Sun));
+
+        let expected = [
+            (1, 1, 1),
+            (2, 2, 1),
+            (7, 2, 1),
+            (8, 2, 2),
+            (9, 3, 2),
+            (31, 6, 5),
+        ];
+        for (day, monday_week, sunday_week) in expected {
+            let dt = Utc.with_ymd_and_hms(2021, 8, day, 0, 0, 0).unwrap();
+            assert_eq!(monday_start.week_of_month(&dt), monday_week, "day {}", day);
+            assert_eq!(sunday_start.week_of_month(&dt), sunday_week, "day {}", day);
+        }
+
+        // The week start is kept through the builder
+        let next_month = RelativeDelta::with_months(1)
+            .and_week_start(WeekStart(Weekday::Sun))
+            .new();
+        let dt = Utc.with_ymd_and_hms(2021, 7, 7, 0, 0, 0).unwrap();
+        assert_eq!(next_month.week_of_month(&dt), 1);
+    }
+
+    #[test]
+    fn test_to_dateutil_str() {
+        assert_eq!(
+            RelativeDelta::default().to_dateutil_str(),
+            "relativedelta()"
+        );
+        assert_eq!(
+            RelativeDelta::with_years(1)
+                .and_months(2)
+                .new()
+                .to_dateutil_str(),
+            "relativedelta(years=+1, months=+2)"
+        );
+        assert_eq!(
+            RelativeDelta::with_days(-3)
+                .and_hours(4)
+                .and_nanoseconds(-5)
+                .new()
+                .to_dateutil_str(),
+            "relativedelta(days=-3, hours=+4, nanoseconds=-5)"
+        );
+        assert_eq!(
+            RelativeDelta::with_months(1)
+                .and_day(Some(31))
+                .and_weekday(Some((Weekday::Fri, -1)))
+                .and_hour(Some(9))
+                .new()
+                .to_dateutil_str(),
+            "relativedelta(months=+1, day=31, weekday=FR(-1), hour=9)"
+        );
+        assert_eq!(
+            RelativeDelta::with_year(2020)
+                .and_weekday(Some((Weekday::Mon, 2)))
+                .new()
+                .to_dateutil_str(),
+            "relativedelta(year=2020, weekday=MO(+2))"
+        );
+    }
+
+    #[test]
+    fn test_with_fractional_second() {
+        let rddt = RelativeDelta::with_fractional_second(3.5).new();
+        assert_eq!(rddt.second(), Some(3));
+        assert_eq!(rddt.nanosecond(), Some(500_000_000));
+
+        let rddt = RelativeDelta::with_fractional_second(0.3).new();
+        assert_eq!(rddt.second(), Some(0));
+        assert_eq!(rddt.nanosecond(), Some(300_000_000));
+
+        let rddt = RelativeDelta::with_fractional_second(59.999_999_999_9).new();
+        assert_eq!(rddt.second(), Some(59));
+        assert_eq!(rddt.nanosecond(), Some(999_999_999));
+    }
+
+    #[test]
+    #[should_panic(expected = "fractional second 60 out of range 0..60")]
+    fn test_with_fractional_second_out_of_range() {
+        RelativeDelta::with_fractional_second(60.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "month 13 out of range 1..=12")]
+    fn test_month_out_of_range() {
+        RelativeDelta::with_month(13).new();
+    }
+
+    #[test]
+    #[should_panic(expected = "day 0 out of range 1..=31")]
+    fn test_day_out_of_range() {
+        RelativeDelta::with_day(0).new();
+    }
+
+    #[test]
+    #[should_panic(expected = "nanosecond 1500000000 out of range 0..=999999999")]
+    fn test_nanosecond_out_of_range() {
+        RelativeDelta::with_nanosecond(1_500_000_000).new();
+    }
+
+    #[test]
+    fn test_overlay() {
+        let base = RelativeDelta::with_years(1).with_days(10);
+        let layer = RelativeDelta::with_months(3);
+        assert_eq!(
+            base.overlay(&layer).new(),
+            RelativeDelta::with_years(1)
+                .and_months(3)
+                .and_days(10)
+                .new()
+        );
+
+        // Explicitly set values are copied, even when set to their default
+        let base = RelativeDelta::with_years(1)
+            .with_days(10)
+            .with_month(Some(2));
+        let mut layer = RelativeDelta::with_days(0);
+        layer.and_month(None).and_hour(Some(8));
+        assert_eq!(
+            base.overlay(&layer).new(),
+            RelativeDelta::with_years(1).and_hour(Some(8)).new()
+        );
+
+        // Overlaying an untouched builder changes nothing
+        let base = RelativeDelta::with_years(1).with_days(10);
+        assert_eq!(
+            base.overlay(&Default::default()).new(),
+            RelativeDelta::with_years(1).and_days(10).new()
+        );
+    }
+
+    #[test]
+    fn test_next_previous_weekday() {
+        let next = RelativeDelta::next_weekday().new();
+        let previous = RelativeDelta::previous_weekday().new();
+        let wednesday = Utc.with_ymd_and_hms(2021, 8, 4, 9, 0, 0).unwrap();
+        let friday = Utc.with_ymd_and_hms(2021, 8, 6, 9, 0, 0).unwrap();
+        let saturday = Utc.with_ymd_and_hms(2021, 8, 7, 9, 0, 0).unwrap();
+        let sunday = Utc.with_ymd_and_hms(2021, 8, 8, 9, 0, 0).unwrap();
+        let monday = Utc.with_ymd_and_hms(2021, 8, 9, 9, 0, 0).unwrap();
+
+        assert_eq!(wednesday + next, wednesday);
+        assert_eq!(wednesday + previous, wednesday);
+        assert_eq!(saturday + next, monday);
+        assert_eq!(sunday + next, monday);
+        assert_eq!(saturday + previous, friday);
+        assert_eq!(sunday + previous, friday);
+        assert!(!next.is_empty());
+
+        // Applied after the other parameters
+        let next_business_day = RelativeDelta::next_weekday().and_days(1).new();
+        assert_eq!(friday + next_business_day, monday);
+    }
+
+    #[test]
+    fn test_next_weekend_day() {
+        let next = RelativeDelta::next_weekend_day().new();
+        let wednesday = Utc.with_ymd_and_hms(2021, 8, 4, 9, 0, 0).unwrap();
+        let saturday = Utc.with_ymd_and_hms(2021, 8, 7, 9, 0, 0).unwrap();
+        let sunday = Utc.with_ymd_and_hms(2021, 8, 8, 9, 0, 0).unwrap();
+
+        assert_eq!(wednesday + next, saturday);
+        assert_eq!(saturday + next, saturday);
+        assert_eq!(sunday + next, sunday);
+    }
+
+    #[test]
+    fn test_days_before_month_end() {
+        let last_day = RelativeDelta::days_before_month_end(0).new();
+        let five_before = RelativeDelta::days_before_month_end(5).new();
+
+        let dt = Utc.with_ymd_and_hms(2021, 1, 15, 0, 0, 0).unwrap();
+        assert_eq!(
+            dt + last_day,
+            Utc.with_ymd_and_hms(2021, 1, 31, 0, 0, 0).unwrap()
+        );
+        assert_eq!(
+            dt + five_before,
+            Utc.with_ymd_and_hms(2021, 1, 26, 0, 0, 0).unwrap()
+        );
+
+        let dt = Utc.with_ymd_and_hms(2021, 2, 1, 0, 0, 0).unwrap();
+        assert_eq!(
+            dt + last_day,
+            Utc.with_ymd_and_hms(2021, 2, 28, 0, 0, 0).unwrap()
+        );
+        assert_eq!(
+            dt + five_before,
+            Utc.with_ymd_and_hms(2021, 2, 23, 0, 0, 0).unwrap()
+        );
+
+        let dt = Utc.with_ymd_and_hms(2020, 2, 29, 0, 0, 0).unwrap();
+        assert_eq!(
+            dt + last_day,
+            Utc.with_ymd_and_hms(2020, 2, 29, 0, 0, 0).unwrap()
+        );
+
+        let dt = Utc.with_ymd_and_hms(2021, 12, 31, 0, 0, 0).unwrap();
+        assert_eq!(
+            dt + five_before,
+            Utc.with_ymd_and_hms(2021, 12, 26, 0, 0, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_weekday_overrides_day() {
+        // 2021-08-15 is a sunday
+        let dt = Utc.with_ymd_and_hms(2021, 8, 3, 0, 0, 0).unwrap();
+
+        let overrides = RelativeDelta::with_day(15)
+            .and_weekday(Some((Weekday::Mon, 1)))
+            .new();
+        assert!(overrides.weekday_overrides_day());
+        assert_eq!(
+            dt + overrides,
+            Utc.with_ymd_and_hms(2021, 8, 16, 0, 0, 0).unwrap()
+        );
+
+        let pinned = RelativeDelta::with_day(15)
+            .and_weekday(Some((Weekday::Mon, 1)))
+            .and_weekday_overrides_day(false)
+            .new();
+        assert!(!pinned.weekday_overrides_day());
+        assert_eq!(
+            dt + pinned,
+            Utc.with_ymd_and_hms(2021, 8, 15, 0, 0, 0).unwrap()
+        );
+
+        // Without a constant day the weekday still applies
+        let no_day = RelativeDelta::with_weekday(Weekday::Mon, 1)
+            .and_weekday_overrides_day(false)
+            .new();
+        assert_eq!(
+            dt + no_day,
+            Utc.with_ymd_and_hms(2021, 8, 9, 0, 0, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_month_enum() {
+        let rd = RelativeDelta::with_month_enum(chrono::Month::March).new();
+        assert_eq!(rd.month(), Some(3));
+
+        let rd = RelativeDelta::with_years(1)
+            .and_month_enum(chrono::Month::December)
+            .new();
+        assert_eq!(rd.month(), Some(12));
+        assert_eq!(rd.years(), 1);
+    }
+
+    #[test]
+    fn test_total_nanoseconds() {
+        let rd = RelativeDelta::with_days(1)
+            .and_hours(2)
+            .and_minutes(3)
+            .and_seconds(4)
+            .and_nanoseconds(5)
+            .new();
+        assert_eq!(rd.total_nanoseconds(), Some(93_784_000_000_005));
+        assert_eq!((-rd).total_nanoseconds(), Some(-93_784_000_000_005));
+        assert_eq!(RelativeDelta::default().total_nanoseconds(), Some(0));
+
+        assert_eq!(
+            RelativeDelta::with_months(1).new().total_nanoseconds(),
+            None
+        );
+        assert_eq!(
+            RelativeDelta::with_hours(1)
+                .and_hour(Some(3))
+                .new()
+                .total_nanoseconds(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_positive_weekday_counts_same_day() {
+        // Expected values as given by python dateutil, e.g.
+        // date(2021, 8, 2) + relativedelta(weekday=MO(+1)) == date(2021, 8, 2)
+        let monday = Utc.with_ymd_and_hms(2021, 8, 2, 0, 0, 0).unwrap();
+        let cases = [
+            (Weekday::Mon, 1, 2),
+            (Weekday::Mon, 2, 9),
+            (Weekday::Wed, 1, 4),
+            (Weekday::Wed, 2, 11),
+            (Weekday::Sun, 1, 8),
+        ];
+        for (weekday, nth, eday) in cases.iter() {
+            let rd = RelativeDelta::with_weekday(*weekday, *nth).new();
+            assert_eq!(
+                monday + rd,
+                Utc.with_ymd_and_hms(2021, 8, *eday, 0, 0, 0).unwrap(),
+                "{:?} {}",
+                weekday,
+                nth
+            );
+        }
+    }
+
+    #[test]
+    fn test_negative_weekday_across_month_lengths() {
+        // Expected values as given by python dateutil, e.g.
+        // date(2021, 2, 1) + relativedelta(day=31, weekday=FR(-1)) == date(2021, 2, 26)
+        let cases = [
+            // 28 day month, ending on a sunday
+            ((2021, 2), Weekday::Fri, -1, (2, 26)),
+            ((2021, 2), Weekday::Fri, -2, (2, 19)),
+            ((2021, 2), Weekday::Sun, -1, (2, 28)),
+            ((2021, 2), Weekday::Sun, -2, (2, 21)),
+            // 30 day month, ending on a friday
+            ((2021, 4), Weekday::Fri, -1, (4, 30)),
+            ((2021, 4), Weekday::Fri, -2, (4, 23)),
+            ((2021, 4), Weekday::Sun, -1, (4, 25)),
+            ((2021, 4), Weekday::Sun, -2, (4, 18)),
+            // 31 day month, ending on a tuesday
+            ((2021, 8), Weekday::Fri, -1, (8, 27)),
+            ((2021, 8), Weekday::Fri, -2, (8, 20)),
+            ((2021, 8), Weekday::Wed, -1, (8, 25)),
+            ((2021, 8), Weekday::Mon, -2, (8, 23)),
+        ];
+        for ((year, month), weekday, nth, (emonth, eday)) in cases.iter() {
+            let dt = Utc.with_ymd_and_hms(*year, *month, 1, 0, 0, 0).unwrap();
+            let rd = RelativeDelta::with_day(31)
+                .and_weekday(Some((*weekday, *nth)))
+                .new();
+            assert_eq!(
+                dt + rd,
+                Utc.with_ymd_and_hms(*year, *emonth, *eday, 0, 0, 0)
+                    .unwrap(),
+                "{:?}({}) in {}-{}",
+                weekday,
+                nth,
+                year,
+                month
+            );
+        }
+    }
+
+    #[test]
+    fn test_seconds_f() {
+        let rd = RelativeDelta::with_seconds_f(2.5).new();
+        assert_eq!(rd.seconds(), 2);
+        assert_eq!(rd.nanoseconds(), 500_000_000);
+
+        let rd = RelativeDelta::with_seconds_f(-2.5).new();
+        assert_eq!(rd.seconds(), -2);
+        assert_eq!(rd.nanoseconds(), -500_000_000);
+
+        let rd = RelativeDelta::with_minutes(1).and_seconds_f(90.25).new();
+        assert_eq!(rd.minutes(), 2);
+        assert_eq!(rd.seconds(), 30);
+        assert_eq!(rd.nanoseconds(), 250_000_000);
+    }
+
+    #[test]
+    fn test_hours_minutes_f() {
+        let rd = RelativeDelta::with_hours_f(1.5).new();
+        assert_eq!(rd.hours(), 1);
+        assert_eq!(rd.minutes(), 30);
+        assert_eq!(rd.seconds(), 0);
+
+        let rd = RelativeDelta::with_minutes_f(2.5).new();
+        assert_eq!(rd.minutes(), 2);
+        assert_eq!(rd.seconds(), 30);
+
+        let rd = RelativeDelta::with_days(1).and_hours_f(-0.25).new();
+        assert_eq!(rd.days(), 1);
+        assert_eq!(rd.minutes(), -15);
+
+        let rd = RelativeDelta::with_minutes(10).and_hours_f(1.5).new();
+        assert_eq!(rd.hours(), 1);
+        assert_eq!(rd.minutes(), 40);
+
+        let rd = RelativeDelta::with_seconds(45).and_minutes_f(2.5).new();
+        assert_eq!(rd.minutes(), 3);
+        assert_eq!(rd.seconds(), 15);
+
+        // Each setter replaces only its own unit, so the order they are called in does not matter
+        let rd = RelativeDelta::with_minutes_f(1.5).and_seconds_f(0.25).new();
+        assert_eq!(
+            rd,
+            RelativeDelta::with_seconds_f(0.25).and_minutes_f(1.5).new()
+        );
+        assert_eq!(rd.minutes(), 1);
+        assert_eq!(rd.seconds(), 30);
+        assert_eq!(rd.nanoseconds(), 250_000_000);
+        let rd = RelativeDelta::with_days_f(0.5)
+            .and_hours_f(1.5)
+            .and_minutes_f(0.5)
+            .and_nanoseconds(5)
+            .new();
+        assert_eq!(
+            rd,
+            RelativeDelta::with_nanoseconds(5)
+                .and_minutes_f(0.5)
+                .and_hours_f(1.5)
+                .and_days_f(0.5)
+                .new()
+        );
+        assert_eq!(
+            rd,
+            RelativeDelta::with_hours(13)
+                .and_minutes(30)
+                .and_seconds(30)
+                .and_nanoseconds(5)
+                .new()
+        );
+
+        // Setting a unit again replaces its fraction as well
+        assert_eq!(
+            RelativeDelta::with_hours_f(1.5).and_hours(2).new(),
+            RelativeDelta::with_hours(2).new()
+        );
+        assert_eq!(
+            RelativeDelta::with_seconds_f(1.5).and_seconds_f(2.25).new(),
+            RelativeDelta::with_seconds_f(2.25).new()
+        );
+    }
+
+    #[test]
+    fn test_days_f() {
+        let rd = RelativeDelta::with_days_f(1.5).new();
+        assert_eq!(rd.days(), 1);
+        assert_eq!(rd.hours(), 12);
+        assert_eq!(rd.minutes(), 0);
+
+        let rd = RelativeDelta::with_days_f(-2.25).new();
+        assert_eq!(rd.days(), -2);
+        assert_eq!(rd.hours(), -6);
+
+        let carried = RelativeDelta::with_hours(3)
+            .and_minutes(5)
+            .and_days_f(1.5)
+            .new();
+        assert_eq!(carried.days(), 1);
+        assert_eq!(carried.hours(), 15);
+        assert_eq!(carried.minutes(), 5);
+
+        let dt = Utc.with_ymd_and_hms(2021, 8, 4, 6, 0, 0).unwrap();
+        assert_eq!(dt + rd, Utc.with_ymd_and_hms(2021, 8, 2, 0, 0, 0).unwrap());
+    }
+
+    #[test]
+    fn test_cmp_on() {
+        use std::cmp::Ordering;
+
+        let month = RelativeDelta::with_months(1).new();
+        let days = RelativeDelta::with_days(30).new();
+
+        let january = Utc.with_ymd_and_hms(2021, 1, 1, 0, 0, 0).unwrap();
+        let february = Utc.with_ymd_and_hms(2021, 2, 1, 0, 0, 0).unwrap();
+        assert_eq!(month.cmp_on(&days, &january), Ordering::Greater);
+        assert_eq!(month.cmp_on(&days, &february), Ordering::Less);
+
+        let april = Utc.with_ymd_and_hms(2021, 4, 1, 0, 0, 0).unwrap();
+        assert_eq!(month.cmp_on(&days, &april), Ordering::Equal);
+
+        // 40 days is longer than any month
+        let forty_days = RelativeDelta::with_days(40).new();
+        assert_eq!(month.cmp_on(&forty_days, &january), Ordering::Less);
+        assert_eq!(month.cmp_on(&forty_days, &february), Ordering::Less);
+    }
+
+    #[test]
+    fn test_weekday_occurrences_between() {
+        let start = Utc.with_ymd_and_hms(2021, 8, 1, 9, 30, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2021, 9, 1, 0, 0, 0).unwrap();
+        let mondays = RelativeDelta::weekday_occurrences_between(&start, &end, Weekday::Mon);
+        assert_eq!(mondays.len(), 5);
+        assert_eq!(
+            mondays[0],
+            Utc.with_ymd_and_hms(2021, 8, 2, 9, 30, 0).unwrap()
+        );
+        assert_eq!(
+            mondays[4],
+            Utc.with_ymd_and_hms(2021, 8, 30, 9, 30, 0).unwrap()
+        );
+
+        // Half open, start included and end excluded
+        let start = Utc.with_ymd_and_hms(2021, 8, 2, 0, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2021, 8, 30, 0, 0, 0).unwrap();
+        let mondays = RelativeDelta::weekday_occurrences_between(&start, &end, Weekday::Mon);
+        assert_eq!(mondays.len(), 4);
+        assert_eq!(mondays[0], start);
+
+        assert!(RelativeDelta::weekday_occurrences_between(&end, &start, Weekday::Mon).is_empty());
+    }
+
+    #[cfg(feature = "hifitime")]
+    #[test]
+    fn test_hifitime_epoch() {
+        use hifitime::Epoch;
+
+        let epoch = Epoch::from_gregorian_utc(2020, 1, 31, 12, 0, 0, 0);
+        assert_eq!(
+            epoch + RelativeDelta::with_months(1).new(),
+            Epoch::from_gregorian_utc(2020, 2, 29, 12, 0, 0, 0)
+        );
+        assert_eq!(
+            epoch + RelativeDelta::with_years(1).new(),
+            Epoch::from_gregorian_utc(2021, 1, 31, 12, 0, 0, 0)
+        );
+        assert_eq!(
+            epoch - RelativeDelta::with_months(1).and_hours(13).new(),
+            Epoch::from_gregorian_utc(2019, 12, 30, 23, 0, 0, 0)
+        );
+
+        // Only fixed parameters are added as a hifitime::Duration
+        assert_eq!(
+            epoch + RelativeDelta::with_hours(36).and_nanoseconds(5).new(),
+            Epoch::from_gregorian_utc(2020, 2, 2, 0, 0, 0, 5)
+        );
+    }
+
+    #[test]
+    fn test_normalize_days_into_months() {
+        // 31 days in january, 28 in february and 31 in march
+        let reference = Utc.with_ymd_and_hms(2021, 1, 15, 0, 0, 0).unwrap();
+        let rd = RelativeDelta::with_days(75).new();
+        let normalized = rd.normalize_days_into_months(&reference);
+        assert_eq!(normalized, RelativeDelta::with_months(2).and_days(16).new());
+        assert_eq!(reference + normalized, reference + rd);
+
+        let rd = RelativeDelta::with_days(59).new();
+        assert_eq!(
+            rd.normalize_days_into_months(&reference),
+            RelativeDelta::with_months(2).new()
+        );
+
+        // Days are walked from the reference shifted by the months already held
+        let rd = RelativeDelta::with_months(11).and_days(59).new();
+        let normalized = rd.normalize_days_into_months(&reference);
+        assert_eq!(normalized, RelativeDelta::with_years(1).and_days(28).new());
+        assert_eq!(reference + normalized, reference + rd);
+
+        let reference = Utc.with_ymd_and_hms(2021, 3, 15, 0, 0, 0).unwrap();
+        let rd = RelativeDelta::with_days(-40).new();
+        let normalized = rd.normalize_days_into_months(&reference);
+        assert_eq!(
+            normalized,
+            RelativeDelta::with_months(-1).and_days(-12).new()
+        );
+        assert_eq!(reference + normalized, reference + rd);
+
+        // Starting from a 31st with months held, the days are added to the clamped february 29th and 30 of them do
+        // not make up the month up to march 31st
+        let reference = Utc.with_ymd_and_hms(2020, 1, 31, 0, 0, 0).unwrap();
+        let rd = RelativeDelta::with_months(1).and_days(30).new();
+        assert_eq!(
+            reference + rd,
+            Utc.with_ymd_and_hms(2020, 3, 30, 0, 0, 0).unwrap()
+        );
+        assert_eq!(rd.normalize_days_into_months(&reference), rd);
+        let rd = RelativeDelta::with_months(1).and_days(62).new();
+        let normalized = rd.normalize_days_into_months(&reference);
+        assert_eq!(normalized, RelativeDelta::with_months(3).and_days(1).new());
+        assert_eq!(reference + normalized, reference + rd);
+
+        // Constant parameters are applied before the days, so 29 days are a month in the leap year february
+        let reference = Utc.with_ymd_and_hms(2020, 7, 15, 0, 0, 0).unwrap();
+        let rd = RelativeDelta::with_month(2).and_days(29).new();
+        let normalized = rd.normalize_days_into_months(&reference);
+        assert_eq!(normalized, RelativeDelta::with_month(2).and_months(1).new());
+        assert_eq!(reference + normalized, reference + rd);
+    }
+
+    #[test]
+    #[should_panic(expected = "ISO week 0 out of range 1..=53")]
+    fn test_and_iso_week_out_of_range() {
+        RelativeDelta::with_days(1).and_iso_week(Some((Weekday::Mon, 0)));
+    }
+
+    #[test]
+    fn test_iso_week_out_of_range_rejected() {
+        use crate::BuildError;
+
+        assert_eq!(
+            "P1D[iso_week=Mon(0)]".parse::<RelativeDelta>(),
+            Err(ParseError::InvalidParameter(
+                "ISO week 0 out of range 1..=53".to_string()
+            ))
+        );
+        assert_eq!(
+            BuildError::IsoWeekOutOfRange(54).to_string(),
+            "ISO week 54 out of range 1..=53"
+        );
+    }
+
+    #[test]
+    fn test_weekday_in_iso_week() {
+        let tuesday_week_1 = RelativeDelta::weekday_in_iso_week(Weekday::Tue, 1).new();
+        let dt = Utc.with_ymd_and_hms(2021, 6, 15, 8, 0, 0).unwrap();
+        assert_eq!(
+            dt + tuesday_week_1,
+            Utc.with_ymd_and_hms(2021, 1, 5, 8, 0, 0).unwrap()
+        );
+        // Week 1 of 2020 starts in december 2019
+        let dt = Utc.with_ymd_and_hms(2020, 6, 15, 8, 0, 0).unwrap();
+        assert_eq!(
+            dt + RelativeDelta::weekday_in_iso_week(Weekday::Mon, 1).new(),
+            Utc.with_ymd_and_hms(2019, 12, 30, 8, 0, 0).unwrap()
+        );
+
+        // 2020 has 53 ISO weeks, ending in january 2021
+        let sunday_week_53 = RelativeDelta::weekday_in_iso_week(Weekday::Sun, 53).new();
+        assert_eq!(
+            dt + sunday_week_53,
+            Utc.with_ymd_and_hms(2021, 1, 3, 8, 0, 0).unwrap()
+        );
+        // 2021 has 52, so week 53 is clamped to the last week
+        let dt = Utc.with_ymd_and_hms(2021, 6, 15, 8, 0, 0).unwrap();
+        assert_eq!(
+            dt + sunday_week_53,
+            Utc.with_ymd_and_hms(2022, 1, 2, 8, 0, 0).unwrap()
+        );
+
+        // The resolved year is used
+        let next_year = RelativeDelta::with_years(1)
+            .and_iso_week(Some((Weekday::Tue, 10)))
+            .new();
+        assert_eq!(
+            dt + next_year,
+            Utc.with_ymd_and_hms(2022, 3, 8, 8, 0, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_approx_total_seconds() {
+        let approx = |rd: RelativeDelta| rd.approx_total_seconds(30.0, 365.0);
+        assert_eq!(
+            approx(RelativeDelta::with_days(1).and_seconds(1).new()),
+            86_401.0
+        );
+        assert_eq!(approx(RelativeDelta::with_months(1).new()), 2_592_000.0);
+        assert_eq!(approx(RelativeDelta::with_years(1).new()), 31_536_000.0);
+
+        let totals: Vec<f64> = (0..36)
+            .map(|months| approx(RelativeDelta::with_months(months).new()))
+            .collect();
+        assert!(totals.windows(2).all(|w| w[0] < w[1]));
+
+        let mut deltas = [
+            RelativeDelta::with_months(1).new(),
+            RelativeDelta::with_days(40).new(),
+            RelativeDelta::with_hours(100).new(),
+        ];
+        deltas.sort_by(|a, b| approx(*a).partial_cmp(&approx(*b)).unwrap());
+        assert_eq!(deltas[0], RelativeDelta::with_hours(100).new());
+        assert_eq!(deltas[2], RelativeDelta::with_days(40).new());
+    }
+
+    #[test]
+    fn test_ordinal_weekday() {
+        use crate::Ordinal;
+
+        let ordinals = [
+            (Ordinal::First, 1),
+            (Ordinal::Second, 2),
+            (Ordinal::Third, 3),
+            (Ordinal::Fourth, 4),
+            (Ordinal::Fifth, 5),
+            (Ordinal::Last, -1),
+            (Ordinal::SecondToLast, -2),
+        ];
+        for (ord, nth) in ordinals.iter() {
+            assert_eq!(
+                RelativeDelta::with_ordinal_weekday(*ord, Weekday::Mon)
+                    .new()
+                    .weekday(),
+                Some((Weekday::Mon, *nth))
+            );
+        }
+
+        // Memorial day, the last monday of may
+        let memorial_day = RelativeDelta::with_month(5)
+            .and_day(Some(31))
+            .and_weekday(Some((Weekday::Mon, Ordinal::Last.nth())))
+            .new();
+        let dt = Utc.with_ymd_and_hms(2021, 1, 1, 0, 0, 0).unwrap();
+        assert_eq!(
+            dt + memorial_day,
+            Utc.with_ymd_and_hms(2021, 5, 31, 0, 0, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_and_subsec() {
+        let measured = std::time::Duration::from_nanos(2_250_000_001);
+        let rd = RelativeDelta::with_minutes(1)
+            .and_seconds(measured.as_secs() as i64)
+            .and_subsec(measured)
+            .new();
+        assert_eq!(rd.minutes(), 1);
+        assert_eq!(rd.seconds(), 2);
+        assert_eq!(rd.nanoseconds(), 250_000_001);
+    }
+
+    #[test]
+    fn test_weekday_jump_from() {
+        let next_friday = RelativeDelta::with_weekday(Weekday::Fri, 1).new();
+        assert_eq!(next_friday.weekday_jump_from(Weekday::Wed), Some(2));
+        assert_eq!(next_friday.weekday_jump_from(Weekday::Fri), Some(0));
+        assert_eq!(next_friday.weekday_jump_from(Weekday::Sat), Some(6));
+
+        let second_monday = RelativeDelta::with_weekday(Weekday::Mon, 2).new();
+        assert_eq!(second_monday.weekday_jump_from(Weekday::Mon), Some(7));
+        assert_eq!(second_monday.weekday_jump_from(Weekday::Sun), Some(8));
+
+        let last_friday = RelativeDelta::with_weekday(Weekday::Fri, -1).new();
+        assert_eq!(last_friday.weekday_jump_from(Weekday::Wed), Some(-5));
+        assert_eq!(last_friday.weekday_jump_from(Weekday::Fri), Some(0));
+        assert_eq!(last_friday.weekday_jump_from(Weekday::Sun), Some(-2));
+
+        let second_last_sunday = RelativeDelta::with_weekday(Weekday::Sun, -2).new();
+        assert_eq!(second_last_sunday.weekday_jump_from(Weekday::Mon), Some(-8));
+
+        assert_eq!(
+            RelativeDelta::with_days(1)
+                .new()
+                .weekday_jump_from(Weekday::Mon),
+            None
+        );
+    }
+
+    #[test]
+    fn test_backend_year_bounds() {
+        use chrono::Datelike;
+
+        let (min, max) = RelativeDelta::backend_year_bounds();
+        assert!(min > i32::MIN as i64 && max < i32::MAX as i64);
+        assert_eq!(min, chrono::NaiveDate::MIN.year() as i64);
+        assert_eq!(max, chrono::NaiveDate::MAX.year() as i64);
+
+        let dt = Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap();
+        let to_max = RelativeDelta::with_year(max as i32).new();
+        assert_eq!((dt + to_max).year() as i64, max);
+    }
+
+    #[test]
+    fn test_weeks_f() {
+        let rd = RelativeDelta::with_weeks_f(1.5).new();
+        assert_eq!(rd.days(), 10);
+        assert_eq!(rd.hours(), 12);
+        assert_eq!(rd.minutes(), 0);
+
+        let rd = RelativeDelta::with_weeks_f(-0.25).new();
+        assert_eq!(rd.days(), -1);
+        assert_eq!(rd.hours(), -18);
+
+        // Whole weeks compose with days like and_weeks, the fraction is distributed on top of what was set separately
+        let rd = RelativeDelta::with_days(2)
+            .and_hours(1)
+            .and_weeks_f(1.5)
+            .new();
+        assert_eq!(rd.days(), 12);
+        assert_eq!(rd.hours(), 13);
+
+        // Setting the weeks again replaces them, fraction included, and days set separately are kept either way
+        let rd = RelativeDelta::with_weeks_f(1.5).and_weeks_f(2.0).new();
+        assert_eq!(rd.days(), 14);
+        assert_eq!(rd.hours(), 0);
+        let rd = RelativeDelta::with_weeks_f(1.5).and_weeks_f(1.5).new();
+        assert_eq!(rd, RelativeDelta::with_weeks_f(1.5).new());
+        assert_eq!(
+            RelativeDelta::with_weeks_f(1.5).and_days(2).new(),
+            RelativeDelta::with_days(2).and_weeks_f(1.5).new()
+        );
+        assert_eq!(
+            RelativeDelta::with_weeks_f(0.5).and_days_f(0.5).new(),
+            RelativeDelta::with_days(4).new()
+        );
+    }
+
+    #[test]
+    fn test_bump() {
+        let rd = RelativeDelta::with_months(11).new();
+        let rd = rd.bump_month(1);
+        assert_eq!(rd, RelativeDelta::with_years(1).new());
+        let rd = rd.bump_month(1);
+        assert_eq!(rd, RelativeDelta::with_years(1).and_months(1).new());
+        assert_eq!(rd.bump_month(-2), RelativeDelta::with_months(11).new());
+        assert_eq!(rd.bump_year(-1), RelativeDelta::with_months(1).new());
+
+        let rd = RelativeDelta::with_hours(23).and_minutes(59).new();
+        assert_eq!(rd.bump_minute(1), RelativeDelta::with_days(1).new());
+        assert_eq!(
+            rd.bump_hour(2).bump_day(1),
+            RelativeDelta::with_days(2)
+                .and_hours(1)
+                .and_minutes(59)
+                .new()
+        );
+        assert_eq!(rd.bump_second(60), RelativeDelta::with_days(1).new());
+    }
+
+    #[test]
+    #[should_panic(expected = "years out of range")]
+    fn test_bump_month_overflow() {
+        RelativeDelta::with_years(i32::MAX)
+            .and_months(11)
+            .new()
+            .bump_month(1);
+    }
+
+    #[test]
+    #[should_panic(expected = "years out of range")]
+    fn test_bump_year_overflow() {
+        RelativeDelta::with_years(i32::MIN).new().bump_year(-1);
+    }
+
+    #[test]
+    fn test_by_total_months() {
+        use crate::ByTotalMonths;
+        use std::collections::BinaryHeap;
+
+        let mut heap = BinaryHeap::new();
+        heap.push(ByTotalMonths(RelativeDelta::with_months(3).new()));
+        heap.push(ByTotalMonths(RelativeDelta::with_years(1).new()));
+        heap.push(ByTotalMonths(RelativeDelta::with_months(-2).new()));
+        heap.push(ByTotalMonths(RelativeDelta::with_months(14).new()));
+
+        let popped: Vec<i64> = std::iter::from_fn(|| heap.pop())
+            .map(|ByTotalMonths(rd)| rd.total_months())
+            .collect();
+        assert_eq!(popped, vec![14, 12, 3, -2]);
+
+        // Time parameters are ignored
+        assert_eq!(
+            ByTotalMonths(RelativeDelta::with_months(1).new()),
+            ByTotalMonths(RelativeDelta::with_months(1).and_days(20).new())
+        );
+    }
+
+    #[test]
+    fn test_two_digit_year() {
+        let year = |yy, pivot| RelativeDelta::with_two_digit_year(yy, pivot).new().year();
+        assert_eq!(year(70, 70), Some(1970));
+        assert_eq!(year(69, 70), Some(2069));
+        assert_eq!(year(20, 70), Some(2020));
+        assert_eq!(year(99, 70), Some(1999));
+        assert_eq!(year(0, 70), Some(2000));
+
+        assert_eq!(year(49, 50), Some(2049));
+        assert_eq!(year(50, 50), Some(1950));
+        // Pivot 0 maps everything to the 1900s, pivot 100 everything to the 2000s
+        assert_eq!(year(0, 0), Some(1900));
+        assert_eq!(year(99, 100), Some(2099));
+    }
+
+    #[test]
+    fn test_resolves_in_same_month() {
+        let next_month = RelativeDelta::with_months(1).new();
+        let jan_1 = Utc.with_ymd_and_hms(2021, 1, 1, 0, 0, 0).unwrap();
+        let jan_31 = Utc.with_ymd_and_hms(2021, 1, 31, 0, 0, 0).unwrap();
+        let feb_1 = Utc.with_ymd_and_hms(2021, 2, 1, 0, 0, 0).unwrap();
+        assert!(next_month.resolves_in_same_month(&jan_1, &jan_31));
+        assert!(!next_month.resolves_in_same_month(&jan_31, &feb_1));
+
+        // Adding days may push one of them into the following month
+        let ten_days = RelativeDelta::with_days(10).new();
+        assert!(!ten_days.resolves_in_same_month(&jan_1, &jan_31));
+
+        // Same month but a different year
+        let dec_1 = Utc.with_ymd_and_hms(2021, 12, 1, 0, 0, 0).unwrap();
+        let next_jan = Utc.with_ymd_and_hms(2022, 1, 1, 0, 0, 0).unwrap();
+        assert!(!RelativeDelta::with_month(3)
+            .new()
+            .resolves_in_same_month(&dec_1, &next_jan));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_deserialize_out_of_range() {
+        let err = serde_json::from_str::<RelativeDelta>(r#"{"month": 13}"#).unwrap_err();
+        assert!(err.to_string().contains("month 13 out of range 1..=12"));
+        assert!(serde_json::from_str::<RelativeDelta>(r#"{"hour": 24}"#).is_err());
+        assert!(serde_json::from_str::<RelativeDelta>(r#"{"iso_week": ["Mon", 0]}"#).is_err());
+        assert!(
+            RelativeDelta::deserialize_strict(&mut serde_json::Deserializer::from_str(
+                r#"{"day": 32}"#
+            ))
+            .is_err()
+        );
+
+        let mut bytes = bincode::serialize(&RelativeDelta::with_month(12).new()).unwrap();
+        // The month follows the 60 bytes of relative parameters and the unset year
+        assert_eq!(&bytes[61..66], &[1, 12, 0, 0, 0]);
+        bytes[62] = 13;
+        assert!(bincode::deserialize::<RelativeDelta>(&bytes).is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_deserialize_strict() {
+        let typo = r#"{"yaers": 1}"#;
+        let lenient: RelativeDelta = serde_json::from_str(typo).unwrap();
+        assert!(lenient.is_empty());
+
+        let strict =
+            RelativeDelta::deserialize_strict(&mut serde_json::Deserializer::from_str(typo));
+        assert!(strict
+            .unwrap_err()
+            .to_string()
+            .contains("unknown field `yaers`"));
+
+        let valid = r#"{"years": 1, "day": 3}"#;
+        assert_eq!(
+            RelativeDelta::deserialize_strict(&mut serde_json::Deserializer::from_str(valid))
+                .unwrap(),
+            RelativeDelta::with_years(1).and_day(Some(3)).new()
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_human_readable_and_compact() {
+        let rd = RelativeDelta::with_years(1)
+            .and_days(-2)
+            .and_day(Some(3))
+            .and_weekday(Some((Weekday::Fri, -1)))
+            .new();
+
+        let json = serde_json::to_value(rd).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({"years": 1, "days": -2, "day": 3, "weekday": ["Fri", -1]})
+        );
+        assert_eq!(serde_json::from_value::<RelativeDelta>(json).unwrap(), rd);
+
+        let bytes = bincode::serialize(&rd).unwrap();
+        // Every parameter in order, without field names
+        assert!(!bytes.windows(5).any(|w| w == b"years"));
+        assert_eq!(&bytes[..4], &1i32.to_le_bytes());
+        assert_eq!(
+            bincode::serialize(&RelativeDelta::default()).unwrap().len(),
+            90
+        );
+        assert_eq!(bincode::deserialize::<RelativeDelta>(&bytes).unwrap(), rd);
+    }
+
+    #[test]
+    fn test_occurrence_count_between() {
+        let week = RelativeDelta::with_days(7).new();
+        let start = Utc.with_ymd_and_hms(2021, 8, 1, 0, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2021, 8, 31, 0, 0, 0).unwrap();
+        assert_eq!(week.occurrence_count_between(&start, &end), 5);
+        // Both ends are included
+        let end = Utc.with_ymd_and_hms(2021, 8, 29, 0, 0, 0).unwrap();
+        assert_eq!(week.occurrence_count_between(&start, &end), 5);
+        let end = Utc.with_ymd_and_hms(2021, 8, 28, 23, 59, 59).unwrap();
+        assert_eq!(week.occurrence_count_between(&start, &end), 4);
+        assert_eq!(week.occurrence_count_between(&end, &start), 0);
+
+        // Monthly points from the 31st are clamped to the end of month, not drifting
+        let month = RelativeDelta::with_months(1).new();
+        let start = Utc.with_ymd_and_hms(2021, 1, 31, 0, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2021, 3, 30, 0, 0, 0).unwrap();
+        assert_eq!(month.occurrence_count_between(&start, &end), 2);
+
+        // Deltas not moving forward only count start
+        assert_eq!(
+            RelativeDelta::default().occurrence_count_between(&start, &end),
+            1
+        );
+        assert_eq!((-week).occurrence_count_between(&start, &end), 1);
+        let first_of_month = RelativeDelta::with_day(1).new();
+        assert_eq!(first_of_month.occurrence_count_between(&start, &end), 1);
+
+        // Stops at the bounds of chrono instead of panicking
+        let end = DateTime::<Utc>::MAX_UTC;
+        let start = end - chrono::Duration::days(10);
+        assert_eq!(week.occurrence_count_between(&start, &end), 2);
+    }
+
+    #[test]
+    fn test_weekday_relative_to() {
+        use crate::Anchor;
+
+        let saturday = Utc.with_ymd_and_hms(2021, 8, 7, 0, 0, 0).unwrap();
+        let after_days = RelativeDelta::with_days(3)
+            .and_weekday(Some((Weekday::Mon, 1)))
+            .new();
+        assert_eq!(after_days.weekday_relative_to(), Anchor::AfterDays);
+        // Tuesday after the days, then on to the next monday
+        assert_eq!(
+            saturday + after_days,
+            Utc.with_ymd_and_hms(2021, 8, 16, 0, 0, 0).unwrap()
+        );
+
+        let before_days = RelativeDelta::with_days(3)
+            .and_weekday(Some((Weekday::Mon, 1)))
+            .and_weekday_relative_to(Anchor::BeforeDays)
+            .new();
+        // Monday from saturday, then the days on top
+        assert_eq!(
+            saturday + before_days,
+            Utc.with_ymd_and_hms(2021, 8, 12, 0, 0, 0).unwrap()
+        );
+        assert_eq!(
+            before_days.checked_add_weekday_in_month(&saturday),
+            Some(Utc.with_ymd_and_hms(2021, 8, 12, 0, 0, 0).unwrap())
+        );
+        assert_eq!(
+            before_days.saturating_add_to(&saturday),
+            Utc.with_ymd_and_hms(2021, 8, 12, 0, 0, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_from_total_months() {
+        let kept = RelativeDelta::from_total_months(30, true).new();
+        assert_eq!(kept.years(), 0);
+        assert_eq!(kept.months(), 30);
+
+        let rolled = RelativeDelta::from_total_months(30, false).new();
+        assert_eq!(rolled.years(), 2);
+        assert_eq!(rolled.months(), 6);
+
+        assert_eq!(kept.total_months(), rolled.total_months());
+        let dt = Utc.with_ymd_and_hms(2020, 1, 31, 0, 0, 0).unwrap();
+        assert_eq!(dt + kept, dt + rolled);
+    }
+
+    #[test]
+    fn test_checked_add_to() {
+        let dt = Utc.with_ymd_and_hms(2020, 1, 31, 12, 0, 0).unwrap();
+        assert_eq!(
+            RelativeDelta::with_years(i32::MAX)
+                .new()
+                .checked_add_to(&dt),
+            None
+        );
+        assert_eq!(
+            RelativeDelta::with_days(i64::MAX / 86_400)
+                .new()
+                .checked_add_to(&dt),
+            None
+        );
+
+        let rddt = RelativeDelta::with_months(1)
+            .and_days(1)
+            .and_weekday(Some((Weekday::Fri, -1)))
+            .new();
+        assert_eq!(rddt.checked_add_to(&dt), Some(dt + rddt));
+        assert_eq!(
+            rddt.checked_add_to(&dt),
+            Some(Utc.with_ymd_and_hms(2020, 2, 28, 12, 0, 0).unwrap())
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Could not add")]
+    fn test_add_out_of_range() {
+        let dt = Utc.with_ymd_and_hms(2020, 1, 31, 12, 0, 0).unwrap();
+        let _ = dt + RelativeDelta::with_years(i32::MAX).new();
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn test_checked_add_to_primitive() {
+        let dt = time::Date::from_calendar_date(2020, time::Month::January, 31)
+            .unwrap()
+            .with_hms(12, 0, 0)
+            .unwrap();
+        assert_eq!(
+            RelativeDelta::with_months(1)
+                .new()
+                .checked_add_to_primitive(&dt),
+            Some(
+                time::Date::from_calendar_date(2020, time::Month::February, 29)
+                    .unwrap()
+                    .with_hms(12, 0, 0)
+                    .unwrap()
+            )
+        );
+        // Within chrono's range but not time's
+        assert_eq!(
+            RelativeDelta::with_years(10_000)
+                .new()
+                .checked_add_to_primitive(&dt),
+            None
+        );
+    }
+
+    #[test]
+    fn test_resolved_weekday() {
+        let second_tuesday = RelativeDelta::with_day(1)
+            .and_weekday(Some((Weekday::Tue, 2)))
+            .new();
+        for month in 1..=12 {
+            let dt = Utc.with_ymd_and_hms(2021, month, 15, 0, 0, 0).unwrap();
+            assert_eq!(second_tuesday.resolved_weekday(&dt), Weekday::Tue);
+        }
+        let dt = Utc.with_ymd_and_hms(2021, 8, 4, 0, 0, 0).unwrap();
+        assert_eq!(
+            RelativeDelta::with_days(1).new().resolved_weekday(&dt),
+            Weekday::Thu
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(RelativeDelta::default().to_string(), "PT0S");
+        let rddt = RelativeDelta::with_years(1)
+            .and_months(3)
+            .and_days(12)
+            .and_hours(5)
+            .and_minutes(30)
+            .and_seconds(45)
+            .new();
+        assert_eq!(rddt.to_string(), "P1Y3M12DT5H30M45S");
+        assert_eq!((-rddt).to_string(), "-P1Y3M12DT5H30M45S");
+        assert_eq!(RelativeDelta::with_hours(2).new().to_string(), "PT2H");
+        assert_eq!(
+            RelativeDelta::with_days(2).and_hours(-1).new().to_string(),
+            "P2DT-1H"
+        );
+        assert_eq!(
+            RelativeDelta::with_nanoseconds(-1_500_000_000)
+                .new()
+                .to_string(),
+            "-PT1.5S"
+        );
+
+        let rddt = RelativeDelta::with_year(2020)
+            .and_months(1)
+            .and_weekday(Some((Weekday::Mon, 1)))
+            .new();
+        assert_eq!(rddt.to_string(), "P1M[year=2020, weekday=Mon(1)]");
+        assert_eq!(RelativeDelta::with_day(1).new().to_string(), "PT0S[day=1]");
+
+        let pinned = RelativeDelta::with_day(3)
+            .and_weekday(Some((Weekday::Mon, 1)))
+            .and_weekday_overrides_day(false)
+            .new();
+        assert_eq!(
+            pinned.to_string(),
+            "PT0S[day=3, weekday=Mon(1), weekday_overrides_day=false]"
+        );
+    }
+
+    #[test]
+    fn test_scale_time_only_and_date_only() {
+        let rddt = RelativeDelta::with_years(1)
+            .and_months(2)
+            .and_days(3)
+            .and_hours(4)
+            .and_minutes(5)
+            .and_seconds(6)
+            .and_day(Some(15))
+            .and_weekday(Some((Weekday::Fri, 1)))
+            .new();
+
+        let time = rddt.scale_time_only(2.0);
+        assert_eq!(
+            time,
+            RelativeDelta::with_years(1)
+                .and_months(2)
+                .and_days(3)
+                .and_hours(8)
+                .and_minutes(10)
+                .and_seconds(12)
+                .and_day(Some(15))
+                .and_weekday(Some((Weekday::Fri, 1)))
+                .new()
+        );
+
+        let date = rddt.scale_date_only(2.0);
+        assert_eq!(
+            date,
+            RelativeDelta::with_years(2)
+                .and_months(4)
+                .and_days(6)
+                .and_hours(4)
+                .and_minutes(5)
+                .and_seconds(6)
+                .and_day(Some(15))
+                .and_weekday(Some((Weekday::Fri, 1)))
+                .new()
+        );
+
+        // Half a year is six months, leaving the time alone
+        assert_eq!(
+            RelativeDelta::with_years(1)
+                .and_hours(3)
+                .new()
+                .scale_date_only(0.5),
+            RelativeDelta::with_months(6).and_hours(3).new()
+        );
+        // Carry from the scaled time goes into days
+        assert_eq!(
+            RelativeDelta::with_months(1)
+                .and_hours(13)
+                .new()
+                .scale_time_only(2.0),
+            RelativeDelta::with_months(1).and_days(1).and_hours(2).new()
+        );
+    }
+
+    #[test]
+    fn test_from_str() {
+        use std::convert::TryFrom;
+
+        let parse = |s: &str| s.parse::<RelativeDelta>();
+        assert_eq!(
+            parse("P1Y2M10DT2H30M").unwrap(),
+            RelativeDelta::with_years(1)
+                .and_months(2)
+                .and_days(10)
+                .and_hours(2)
+                .and_minutes(30)
+                .new()
+        );
+        assert_eq!(parse("P3W").unwrap(), RelativeDelta::with_days(21).new());
+        assert_eq!(parse("P1W2D").unwrap(), RelativeDelta::with_days(9).new());
+        assert_eq!(
+            parse("-P1DT12H").unwrap(),
+            RelativeDelta::with_days(-1).and_hours(-12).new()
+        );
+        assert_eq!(
+            parse("PT1,5S").unwrap(),
+            RelativeDelta::with_seconds(1)
+                .and_nanoseconds(500_000_000)
+                .new()
+        );
+        assert_eq!(parse("P0.5Y").unwrap(), RelativeDelta::with_months(6).new());
+        assert_eq!(
+            parse("PT36H").unwrap(),
+            RelativeDelta::with_days(1).and_hours(12).new()
+        );
+
+        assert_eq!(
+            RelativeDelta::try_from("P1Y2M"),
+            Ok(RelativeDelta::with_years(1).and_months(2).new())
+        );
+        assert_eq!(
+            RelativeDelta::try_from("1Y"),
+            Err(ParseError::MissingPeriod)
+        );
+
+        assert_eq!(parse("1Y"), Err(ParseError::MissingPeriod));
+        assert_eq!(parse("P"), Err(ParseError::Empty));
+        assert_eq!(parse("P1DT"), Err(ParseError::Empty));
+        assert_eq!(
+            parse("P1"),
+            Err(ParseError::MissingDesignator("1".to_string()))
+        );
+        assert_eq!(parse("P1D2Y"), Err(ParseError::UnexpectedDesignator('Y')));
+        assert_eq!(parse("P1H"), Err(ParseError::UnexpectedDesignator('H')));
+        assert_eq!(parse("P1X"), Err(ParseError::UnexpectedDesignator('X')));
+        assert_eq!(
+            parse("P-D"),
+            Err(ParseError::InvalidNumber("-".to_string()))
+        );
+        assert_eq!(
+            parse("P1.D"),
+            Err(ParseError::InvalidNumber("1.".to_string()))
+        );
+        assert_eq!(parse("P9999999999Y"), Err(ParseError::Overflow));
+        assert_eq!(
+            parse("P1D[foo=1]"),
+            Err(ParseError::InvalidParameter("foo=1".to_string()))
+        );
+        assert_eq!(
+            parse("P1D[month=13]"),
+            Err(ParseError::InvalidParameter(
+                "month 13 out of range 1..=12".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn test_from_str_round_trip() {
+        use crate::Anchor;
+
+        let deltas = [
+            RelativeDelta::default(),
+            RelativeDelta::with_years(1)
+                .and_months(3)
+                .and_days(12)
+                .and_hours(5)
+                .and_minutes(30)
+                .and_seconds(45)
+                .new(),
+            RelativeDelta::with_years(-2)
+                .and_days(-3)
+                .and_nanoseconds(-1_250_000)
+                .new(),
+            RelativeDelta::with_days(2).and_hours(-1).new(),
+            RelativeDelta::with_year(2020)
+                .and_months(1)
+                .and_day(Some(31))
+                .and_weekday(Some((Weekday::Mon, -1)))
+                .new(),
+            RelativeDelta::next_weekday()
+                .and_weekday_overrides_day(true)
+                .new(),
+            RelativeDelta::with_day(3)
+                .and_weekday(Some((Weekday::Mon, 1)))
+                .and_weekday_overrides_day(false)
+                .new(),
+            RelativeDelta::weekday_in_iso_week(Weekday::Wed, 10)
+                .and_week_start(WeekStart(Weekday::Sun))
+                .and_weekday_relative_to(Anchor::BeforeDays)
+                .new(),
+        ];
+        for rddt in deltas.iter() {
+            assert_eq!(
+                rddt.to_string().parse::<RelativeDelta>().as_ref(),
+                Ok(rddt),
+                "{}",
+                rddt
+            );
+        }
+    }
+
+    #[test]
+    fn test_is_identity_on() {
+        let dt = Utc.with_ymd_and_hms(2021, 3, 1, 9, 30, 0).unwrap();
+        // Already the first monday of the month at half past nine
+        let first_monday = RelativeDelta::with_day(1)
+            .and_weekday(Some((Weekday::Mon, 1)))
+            .and_hour(Some(9))
+            .and_minute(Some(30))
+            .new();
+        assert!(first_monday.is_identity_on(&dt));
+        // A month ahead lands on april 1st, which is 31 days later
+        assert!(RelativeDelta::with_months(1)
+            .and_days(-31)
+            .new()
+            .is_identity_on(&dt));
+        assert!(RelativeDelta::default().is_identity_on(&dt));
+
+        assert!(!RelativeDelta::with_day(2).new().is_identity_on(&dt));
+        assert!(!RelativeDelta::with_weekday(Weekday::Mon, 2)
+            .new()
+            .is_identity_on(&dt));
+        assert!(!RelativeDelta::with_year(300_000).new().is_identity_on(&dt));
+    }
+
+    #[test]
+    fn test_weeks() {
+        assert_eq!(
+            RelativeDelta::with_weeks(2).new(),
+            RelativeDelta::with_days(14).new()
+        );
+        assert_eq!(
+            RelativeDelta::with_weeks(2).and_days(3).new(),
+            RelativeDelta::with_days(17).new()
+        );
+        assert_eq!(
+            RelativeDelta::with_days(3).and_weeks(-1).new(),
+            RelativeDelta::with_days(-4).new()
+        );
+        assert_eq!(
+            RelativeDelta::with_days(1)
+                .with_weeks(1)
+                .and_hours(30)
+                .new(),
+            RelativeDelta::with_days(9).and_hours(6).new()
+        );
+
+        assert_eq!(RelativeDelta::with_weeks(2).and_days(3).new().weeks(), 2);
+        assert_eq!(RelativeDelta::with_days(-10).new().weeks(), -1);
+        assert_eq!(RelativeDelta::with_days(6).new().weeks(), 0);
+    }
+
+    #[test]
+    fn test_with_month_or_keep() {
+        let dt = Utc.with_ymd_and_hms(2021, 5, 31, 0, 0, 0).unwrap();
+        let keep = RelativeDelta::with_month_or_keep(0).new();
+        assert_eq!(keep.month(), None);
+        assert_eq!(dt + keep, dt);
+        assert_eq!(RelativeDelta::with_month_or_keep(1).new().month(), Some(1));
+        assert_eq!(
+            dt + RelativeDelta::with_month_or_keep(12).new(),
+            Utc.with_ymd_and_hms(2021, 12, 31, 0, 0, 0).unwrap()
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "month 13 out of range 0..=12")]
+    fn test_with_month_or_keep_out_of_range() {
+        RelativeDelta::with_month_or_keep(13);
+    }
+
+    #[test]
+    fn test_milliseconds_and_microseconds() {
+        let rddt = RelativeDelta::with_milliseconds(1500).new();
+        assert_eq!((rddt.seconds(), rddt.nanoseconds()), (1, 500_000_000));
+        let rddt = RelativeDelta::with_microseconds(-2_000_250).new();
+        assert_eq!((rddt.seconds(), rddt.nanoseconds()), (-2, -250_000));
+        assert_eq!(
+            RelativeDelta::with_minutes(1)
+                .and_milliseconds(61_001)
+                .new(),
+            RelativeDelta::with_minutes(2)
+                .and_seconds(1)
+                .and_nanoseconds(1_000_000)
+                .new()
+        );
+        assert_eq!(
+            RelativeDelta::with_seconds(1).and_microseconds(-1).new(),
+            RelativeDelta::with_seconds(1).and_nanoseconds(-1_000).new()
+        );
+
+        // Added onto the seconds and nanoseconds set separately, in either order
+        assert_eq!(
+            RelativeDelta::with_seconds(1)
+                .and_nanoseconds(5)
+                .and_milliseconds(1_500)
+                .new(),
+            RelativeDelta::with_seconds(2)
+                .and_nanoseconds(500_000_005)
+                .new()
+        );
+        assert_eq!(
+            RelativeDelta::with_nanoseconds(7)
+                .and_microseconds(2)
+                .new()
+                .nanoseconds(),
+            2_007
+        );
+        assert_eq!(
+            RelativeDelta::with_milliseconds(1_500)
+                .and_seconds(1)
+                .and_nanoseconds(5)
+                .new(),
+            RelativeDelta::with_seconds(2)
+                .and_nanoseconds(500_000_005)
+                .new()
+        );
+        assert_eq!(
+            RelativeDelta::with_microseconds(2)
+                .and_nanoseconds(7)
+                .new()
+                .nanoseconds(),
+            2_007
+        );
+
+        // Setting them again replaces them
+        assert_eq!(
+            RelativeDelta::with_milliseconds(1_500)
+                .and_milliseconds(250)
+                .new(),
+            RelativeDelta::with_milliseconds(250).new()
+        );
+        assert_eq!(
+            RelativeDelta::with_microseconds(1_500)
+                .and_microseconds(250)
+                .new(),
+            RelativeDelta::with_microseconds(250).new()
+        );
+
+        // No overflow converting the extremes
+        assert_eq!(
+            RelativeDelta::with_milliseconds(i64::MAX)
+                .new()
+                .total_nanoseconds(),
+            Some(i64::MAX as i128 * 1_000_000)
+        );
+        assert_eq!(
+            RelativeDelta::with_microseconds(i64::MIN)
+                .new()
+                .total_nanoseconds(),
+            Some(i64::MIN as i128 * 1_000)
+        );
+        assert_eq!(
+            RelativeDelta::with_seconds(i64::MAX)
+                .and_milliseconds(i64::MAX)
+                .new()
+                .total_nanoseconds(),
+            Some(i64::MAX as i128 * 1_000_000_000 + i64::MAX as i128 * 1_000_000)
+        );
+    }
+
+    #[test]
+    fn test_to_iso8601_canonical() {
+        let rddt = RelativeDelta::with_years(-1)
+            .and_days(-2)
+            .and_hours(-3)
+            .and_nanoseconds(-500_000_000)
+            .new();
+        assert_eq!(rddt.to_iso8601_canonical().unwrap(), "-P1Y2DT3H0.5S");
+        assert_eq!((-rddt).to_iso8601_canonical().unwrap(), "P1Y2DT3H0.5S");
+        assert_eq!(
+            RelativeDelta::default().to_iso8601_canonical().unwrap(),
+            "PT0S"
+        );
+
+        // Mixed signs and constant parameters cannot be represented
+        assert_eq!(
+            RelativeDelta::with_days(2)
+                .and_hours(-1)
+                .new()
+                .to_iso8601_canonical(),
+            None
+        );
+        assert_eq!(
+            RelativeDelta::with_years(1)
+                .and_months(-1)
+                .new()
+                .to_iso8601_canonical(),
+            None
+        );
+        assert_eq!(
+            RelativeDelta::with_days(1)
+                .and_day(Some(1))
+                .new()
+                .to_iso8601_canonical(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_partial_ord() {
+        use std::cmp::Ordering;
+
+        let months13 = RelativeDelta::with_months(13).new();
+        let years1 = RelativeDelta::with_years(1).new();
+        assert!(months13 > years1);
+        assert!(years1 < months13);
+        assert_eq!(
+            months13.partial_cmp(&RelativeDelta::with_years(1).and_months(1).new()),
+            Some(Ordering::Equal)
+        );
+        assert!(RelativeDelta::with_months(1).new() > RelativeDelta::with_days(30).new());
+        assert!(RelativeDelta::with_months(1).new() < RelativeDelta::with_days(31).new());
+        assert!(RelativeDelta::with_days(-1).new() < RelativeDelta::with_nanoseconds(1).new());
+
+        // Same length but not equal
+        let hours23 = RelativeDelta::with_hours(23).new();
+        assert_eq!(
+            RelativeDelta::with_days(1)
+                .and_hours(-1)
+                .new()
+                .partial_cmp(&hours23),
+            None
+        );
+        // Constant parameters and fractional months are not ordered
+        assert_eq!(RelativeDelta::with_day(1).new().partial_cmp(&years1), None);
+        assert_eq!(
+            RelativeDelta::with_weekday(Weekday::Mon, 1)
+                .new()
+                .partial_cmp(&years1),
+            None
+        );
+        assert_eq!(
+            RelativeDelta::ysmsdshsmsssns_f(0.0, 1.5, 0.0, 0.0, 0.0, 0.0, 0)
+                .new()
+                .partial_cmp(&years1),
+            None
+        );
+    }
+
+    #[test]
+    fn test_weekday_counted_from_anchor() {
+        let third_friday = RelativeDelta::with_weekday(Weekday::Fri, 3).new();
+        // Wednesday the 14th, so the fridays counted are the 16th, 23rd and 30th
+        let dt = Utc.with_ymd_and_hms(2021, 7, 14, 0, 0, 0).unwrap();
+        assert_eq!(
+            dt + third_friday,
+            Utc.with_ymd_and_hms(2021, 7, 30, 0, 0, 0).unwrap()
+        );
+        // The anchor itself counts when it falls on the weekday
+        let dt = Utc.with_ymd_and_hms(2021, 7, 16, 0, 0, 0).unwrap();
+        assert_eq!(
+            dt + third_friday,
+            Utc.with_ymd_and_hms(2021, 7, 30, 0, 0, 0).unwrap()
+        );
+        // Scoped to the month, the 3rd friday is the 16th
+        let in_month = RelativeDelta::with_weekday(Weekday::Fri, 3)
+            .and_day(Some(1))
+            .new();
+        assert_eq!(
+            dt + in_month,
+            Utc.with_ymd_and_hms(2021, 7, 16, 0, 0, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_add_assign_and_sub_assign() {
+        let mut acc = RelativeDelta::default();
+        for _ in 0..10 {
+            acc += RelativeDelta::with_days(1).new();
+        }
+        assert_eq!(acc, RelativeDelta::with_days(10).new());
+
+        let hours = RelativeDelta::with_hours(13).new();
+        acc += &hours;
+        acc += &hours;
+        assert_eq!(acc, RelativeDelta::with_days(11).and_hours(2).new());
+        acc -= RelativeDelta::with_days(11).new();
+        acc -= &hours;
+        assert_eq!(acc, RelativeDelta::with_hours(-11).new());
+
+        // Constant parameters are dropped, as with `+`
+        let mut rddt = RelativeDelta::with_day(1).and_days(1).new();
+        rddt += RelativeDelta::with_days(1).new();
+        assert_eq!(rddt, RelativeDelta::with_days(2).new());
+    }
+
+    #[test]
+    fn test_verify_round_trip() {
+        let mid_month = Utc.with_ymd_and_hms(2021, 1, 15, 12, 0, 0).unwrap();
+        let month_end = Utc.with_ymd_and_hms(2021, 1, 31, 12, 0, 0).unwrap();
+        let month = RelativeDelta::with_months(1).new();
+        assert!(month.verify_round_trip(&mid_month));
+        // Clamped to February 28th, coming back to January 28th
+        assert!(!month.verify_round_trip(&month_end));
+
+        assert!(RelativeDelta::with_years(2)
+            .and_months(-5)
+            .new()
+            .verify_round_trip(&mid_month));
+        // Days and time alone never clamp
+        assert!(RelativeDelta::with_days(45)
+            .and_minutes(3)
+            .new()
+            .verify_round_trip(&month_end));
+
+        // February 1st and 30 days is March 3rd, while a month and 30 days back from it is January 4th
+        let new_year = Utc.with_ymd_and_hms(2021, 1, 1, 0, 0, 0).unwrap();
+        assert!(!RelativeDelta::with_months(1)
+            .and_days(30)
+            .new()
+            .verify_round_trip(&new_year));
+        assert!(!RelativeDelta::with_day(1)
+            .new()
+            .verify_round_trip(&mid_month));
+        assert!(!RelativeDelta::with_years(300_000)
+            .new()
+            .verify_round_trip(&mid_month));
+    }
+
+    #[test]
+    fn test_mul_assign_and_div_assign() {
+        let rddt = RelativeDelta::with_years(3)
+            .and_months(1)
+            .and_days(5)
+            .and_hours(7)
+            .and_month(Some(2))
+            .and_day(Some(10))
+            .new();
+
+        let mut scaled = rddt;
+        scaled *= 0.5;
+        assert_eq!(scaled, rddt * 0.5);
+        scaled *= 2.0;
+        assert_eq!(scaled, rddt * 0.5 * 2.0);
+
+        let mut divided = rddt;
+        divided /= 4.0;
+        assert_eq!(divided, rddt / 4.0);
+        assert_eq!(divided.month(), Some(2));
+        assert_eq!(divided.day(), Some(10));
+    }
+
+    #[test]
+    fn test_every() {
+        use crate::Unit;
+
+        assert_eq!(
+            RelativeDelta::every(2, Unit::Week).new(),
+            RelativeDelta::with_days(14).new()
+        );
+        assert_eq!(
+            RelativeDelta::every(3, Unit::Month).new(),
+            RelativeDelta::with_months(3).new()
+        );
+        assert_eq!(
+            RelativeDelta::every(2, Unit::Year).new(),
+            RelativeDelta::with_years(2).new()
+        );
+        assert_eq!(
+            RelativeDelta::every(1, Unit::Day).new(),
+            RelativeDelta::with_days(1).new()
+        );
+        assert_eq!(
+            RelativeDelta::every(36, Unit::Hour).new(),
+            RelativeDelta::with_days(1).and_hours(12).new()
+        );
+        assert_eq!(
+            RelativeDelta::every(-15, Unit::Minute).new(),
+            RelativeDelta::with_minutes(-15).new()
+        );
+        assert_eq!(
+            RelativeDelta::every(30, Unit::Second).new(),
+            RelativeDelta::with_seconds(30).new()
+        );
+    }
+
+    #[test]
+    fn test_mul_i64() {
+        let days = RelativeDelta::with_days(9_007_199_254_740_993).new();
+        assert_eq!((days * 2).days(), 18_014_398_509_481_986);
+        assert_ne!((days * 2.0).days(), 18_014_398_509_481_986);
+        assert_eq!(-3 * days, days * -3);
+
+        let rddt = RelativeDelta::with_years(1)
+            .and_months(5)
+            .and_hours(13)
+            .and_day(Some(1))
+            .and_weekday(Some((Weekday::Mon, 1)))
+            .new();
+        assert_eq!(
+            rddt * 2,
+            RelativeDelta::with_years(2)
+                .and_months(10)
+                .and_days(1)
+                .and_hours(2)
+                .and_day(Some(1))
+                .and_weekday(Some((Weekday::Mon, 1)))
+                .new()
+        );
+        assert_eq!((rddt * 3).total_months(), 3 * rddt.total_months());
+        // Float multiplication keeps the weekday and constant parameters the same way
+        assert_eq!(rddt * 2.0, rddt * 2);
+        assert_eq!(2.0 * rddt, 2 * rddt);
+        let rddt = RelativeDelta::with_months(1)
+            .and_months_f(0.5)
+            .and_weekday(Some((Weekday::Fri, -1)))
+            .new();
+        assert_eq!(rddt * 4.0, rddt * 4);
+
+        // Factors beyond i32 are not truncated
+        let month = RelativeDelta::with_months(1).new();
+        assert_eq!((month * (1i64 << 32)).total_months(), 1i64 << 32);
+    }
+
+    #[test]
+    #[should_panic(expected = "Could not multiply")]
+    fn test_mul_i64_overflow() {
+        let _ = RelativeDelta::with_years(1).new() * (1i64 << 32);
+    }
+
+    #[test]
+    fn test_nonzero_component_count() {
+        assert_eq!(RelativeDelta::default().nonzero_component_count(), 0);
+        assert!(RelativeDelta::default().is_empty());
+        assert_eq!(
+            RelativeDelta::with_days(3).new().nonzero_component_count(),
+            1
+        );
+        assert_eq!(
+            RelativeDelta::with_year(2020)
+                .new()
+                .nonzero_component_count(),
+            1
+        );
+
+        let rddt = RelativeDelta::with_years(1)
+            .and_months(2)
+            .and_hours(-3)
+            .and_day(Some(1))
+            .and_weekday(Some((Weekday::Fri, -1)))
+            .new();
+        assert_eq!(rddt.nonzero_component_count(), 5);
+        assert!(!rddt.is_empty());
+        // Zero relative parameters and the week start are not counted
+        let rddt = RelativeDelta::with_days(0)
+            .and_week_start(WeekStart(Weekday::Sun))
+            .new();
+        assert_eq!(rddt.nonzero_component_count(), 0);
+    }
+
+    #[test]
+    fn test_sum() {
+        let deltas = vec![
+            RelativeDelta::with_years(1).and_hours(20).new(),
+            RelativeDelta::with_months(11).and_day(Some(1)).new(),
+            RelativeDelta::with_days(-2).and_hours(5).new(),
+        ];
+        let chained = deltas[0] + deltas[1] + deltas[2];
+        assert_eq!(deltas.iter().sum::<RelativeDelta>(), chained);
+        assert_eq!(deltas.into_iter().sum::<RelativeDelta>(), chained);
+        assert_eq!(
+            chained,
+            RelativeDelta::with_years(1)
+                .and_months(11)
+                .and_days(-1)
+                .and_hours(1)
+                .new()
+        );
+        assert_eq!(
+            Vec::<RelativeDelta>::new()
+                .into_iter()
+                .sum::<RelativeDelta>(),
+            RelativeDelta::default()
+        );
+    }
+
+    #[test]
+    fn test_checked_saturating_and_wrapping_mul() {
+        let years = RelativeDelta::with_years(i32::MAX).new();
+        assert_eq!(years.checked_mul(2), None);
+        assert_eq!(years.saturating_mul(2), years);
+        assert_eq!(years.wrapping_mul(2), RelativeDelta::with_years(-2).new());
+        assert_eq!(
+            years.saturating_mul(-2),
+            RelativeDelta::with_years(i32::MIN).new()
+        );
+
+        let rddt = RelativeDelta::with_years(1)
+            .and_months(7)
+            .and_hours(13)
+            .and_nanoseconds(600_000_000)
+            .and_day(Some(1))
+            .new();
+        let expected = rddt * 3;
+        assert_eq!(rddt.checked_mul(3), Some(expected));
+        assert_eq!(rddt.saturating_mul(3), expected);
+        assert_eq!(rddt.wrapping_mul(3), expected);
+        assert_eq!(rddt.checked_mul(-3), Some(rddt * -3));
+
+        // Hours carried over into days that no longer fit
+        let days = RelativeDelta::with_days(i64::MAX / 3).and_hours(23).new();
+        assert_eq!(days.checked_mul(3), None);
+        assert_eq!(days.saturating_mul(3).days(), i64::MAX);
+        assert_eq!(days.saturating_mul(3).hours(), 21);
+        assert_eq!(days.wrapping_mul(3).days(), i64::MIN);
+    }
+
+    #[test]
+    fn test_normalized() {
+        let one_and_a_half = RelativeDelta::ysmsdshsmsssns_f(0.0, 1.5, 0.0, 0.0, 0.0, 0.0, 0).new();
+        assert_ne!(one_and_a_half, RelativeDelta::with_months(1).new());
+
+        // Landing in april, which has 30 days, with no fractional months left
+        let dt = Utc.with_ymd_and_hms(2021, 3, 10, 0, 0, 0).unwrap();
+        let normalized = one_and_a_half.normalized(&dt);
+        assert_eq!(
+            normalized,
+            Some(RelativeDelta::with_months(1).and_days(15).new())
+        );
+        // Landing in february, which has 28 days in 2021
+        let dt = Utc.with_ymd_and_hms(2021, 1, 10, 0, 0, 0).unwrap();
+        assert_eq!(
+            one_and_a_half.normalized(&dt),
+            Some(RelativeDelta::with_months(1).and_days(14).new())
+        );
+        // A quarter of may is 7.75 days
+        let dt = Utc.with_ymd_and_hms(2021, 4, 10, 0, 0, 0).unwrap();
+        let rddt = RelativeDelta::ysmsdshsmsssns_f(0.0, 1.25, 1.0, 0.0, 0.0, 0.0, 0).new();
+        assert_eq!(
+            rddt.normalized(&dt),
+            Some(
+                RelativeDelta::with_months(1)
+                    .and_days(8)
+                    .and_hours(18)
+                    .new()
+            )
+        );
+
+        assert_eq!(
+            RelativeDelta::with_days(3).new().normalized(&dt),
+            Some(RelativeDelta::with_days(3).new())
+        );
+
+        // The constant year and month are taken into account, landing in the 29 days of february 2024
+        let half = RelativeDelta::with_month(2)
+            .and_year(Some(2024))
+            .and_months_f(0.5)
+            .new();
+        assert_eq!(
+            half.normalized(&dt),
+            Some(
+                RelativeDelta::with_month(2)
+                    .and_year(Some(2024))
+                    .and_days(14)
+                    .and_hours(12)
+                    .new()
+            )
+        );
+
+        // Out of range instead of a panic
+        let far = RelativeDelta::with_years(300_000).and_months_f(0.5).new();
+        assert_eq!(far.normalized(&dt), None);
+    }
+
+    #[test]
+    fn test_verify_round_trip_negation() {
+        let month = RelativeDelta::with_months(1).new();
+        let jan31 = Utc.with_ymd_and_hms(2021, 1, 31, 0, 0, 0).unwrap();
+        assert_eq!(
+            jan31 + month + (-month),
+            Utc.with_ymd_and_hms(2021, 1, 28, 0, 0, 0).unwrap()
+        );
+        assert!(!month.verify_round_trip(&jan31));
+
+        let jan15 = Utc.with_ymd_and_hms(2021, 1, 15, 0, 0, 0).unwrap();
+        assert!(month.verify_round_trip(&jan15));
+        assert!(RelativeDelta::with_years(-3)
+            .and_months(2)
+            .new()
+            .verify_round_trip(&jan31));
+    }
+
+    #[test]
+    fn test_add_to_naive_date() {
+        use chrono::NaiveDate;
+
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        assert_eq!(
+            date(2020, 1, 31) + RelativeDelta::with_months(1),
+            date(2020, 2, 29)
+        );
+        assert_eq!(
+            date(2021, 1, 31) + RelativeDelta::with_months(1).new(),
+            date(2021, 2, 28)
+        );
+        assert_eq!(
+            date(2021, 3, 31) + RelativeDelta::with_years(-1).and_months(-1).new(),
+            date(2020, 2, 29)
+        );
+        // Next friday, counting the date itself, and the last monday of the month
+        assert_eq!(
+            date(2021, 7, 14) + RelativeDelta::with_weekday(Weekday::Fri, 1),
+            date(2021, 7, 16)
+        );
+        assert_eq!(
+            date(2021, 7, 16) + RelativeDelta::with_weekday(Weekday::Fri, 1),
+            date(2021, 7, 16)
+        );
+        assert_eq!(
+            date(2021, 7, 14) + RelativeDelta::with_day(31).and_weekday(Some((Weekday::Mon, -1))),
+            date(2021, 7, 26)
+        );
+        // Time parameters are ignored, so 23:00 and 5 hours does not roll over into the next day
+        assert_eq!(
+            date(2021, 7, 14) + RelativeDelta::with_days(1).and_hours(5).and_hour(Some(23)),
+            date(2021, 7, 15)
+        );
+        // Whole days folded out of relative hours are kept, only the sub-day remainder is dropped
+        assert_eq!(
+            date(2021, 7, 14) + RelativeDelta::with_hours(23),
+            date(2021, 7, 14)
+        );
+        assert_eq!(
+            date(2021, 7, 14) + RelativeDelta::with_hours(24),
+            date(2021, 7, 15)
+        );
+        assert_eq!(
+            date(2021, 7, 14) + RelativeDelta::with_hours(-25),
+            date(2021, 7, 13)
+        );
+    }
+
+    #[test]
+    fn test_add_to_naive_time() {
+        use chrono::NaiveTime;
+
+        let time = |h, m, s| NaiveTime::from_hms_opt(h, m, s).unwrap();
+        // 25 hours from 23:00 is midnight two days later
+        let rddt = RelativeDelta::with_hours(25).new();
+        assert_eq!(
+            rddt.add_time_with_overflow(time(23, 0, 0)),
+            (time(0, 0, 0), 2)
+        );
+        assert_eq!(
+            RelativeDelta::with_hours(1)
+                .new()
+                .add_time_with_overflow(time(23, 0, 0)),
+            (time(0, 0, 0), 1)
+        );
+        assert_eq!(time(23, 0, 0) + rddt, time(0, 0, 0));
+        assert_eq!(
+            time(22, 59, 59) + RelativeDelta::with_hours(25),
+            time(23, 59, 59)
+        );
+
+        let back = RelativeDelta::with_minutes(-90).new();
+        assert_eq!(
+            back.add_time_with_overflow(time(1, 0, 0)),
+            (time(23, 30, 0), -1)
+        );
+        assert_eq!(
+            RelativeDelta::with_nanoseconds(1)
+                .new()
+                .add_time_with_overflow(time(0, 0, 0)),
+            (NaiveTime::from_hms_nano_opt(0, 0, 0, 1).unwrap(), 0)
+        );
+
+        // Constant parameters override first
+        let rddt = RelativeDelta::with_hour(22)
+            .and_minute(Some(15))
+            .and_hours(3)
+            .new();
+        assert_eq!(
+            rddt.add_time_with_overflow(time(9, 40, 10)),
+            (time(1, 15, 10), 1)
+        );
+        // Days only count towards the overflow
+        assert_eq!(
+            RelativeDelta::with_hours(48)
+                .new()
+                .add_time_with_overflow(time(9, 0, 0)),
+            (time(9, 0, 0), 2)
+        );
+        assert_eq!(
+            RelativeDelta::with_days(-3)
+                .and_hours(-10)
+                .new()
+                .add_time_with_overflow(time(9, 0, 0)),
+            (time(23, 0, 0), -4)
+        );
+        assert_eq!(
+            time(9, 0, 0) + RelativeDelta::with_days(3).and_minutes(5),
+            time(9, 5, 0)
+        );
+    }
+
+    #[test]
+    fn test_and_weekday_parts() {
+        assert_eq!(
+            RelativeDelta::with_months(1)
+                .and_weekday_parts(Weekday::Mon, -2)
+                .new(),
+            RelativeDelta::with_months(1)
+                .and_weekday(Some((Weekday::Mon, -2)))
+                .new()
+        );
+        assert_eq!(
+            RelativeDelta::with_weekday(Weekday::Fri, 1)
+                .and_no_weekday()
+                .new(),
+            RelativeDelta::with_weekday(Weekday::Fri, 1)
+                .and_weekday(None)
+                .new()
+        );
+        assert_eq!(
+            RelativeDelta::with_weekday(Weekday::Fri, 1)
+                .and_no_weekday()
+                .new()
+                .weekday(),
+            None
+        );
+
+        // Clearing takes precedence over the weekday of the base in an overlay
+        let mut base = RelativeDelta::with_days(1);
+        base.and_weekday_parts(Weekday::Tue, 1);
+        let overlaid = base.overlay(RelativeDelta::with_days(2).and_no_weekday());
+        assert_eq!(overlaid.new(), RelativeDelta::with_days(2).new());
+    }
+
+    #[test]
+    fn test_total_seconds() {
+        assert_eq!(
+            RelativeDelta::with_minutes(90).new().total_seconds(),
+            5400.0
+        );
+        assert!((RelativeDelta::with_years(1).new().total_seconds() - 31_556_952.0).abs() < 1e-6);
+        assert!((RelativeDelta::with_months(1).new().total_seconds() - 2_629_746.0).abs() < 1e-6);
+        assert_eq!(
+            RelativeDelta::with_days(-1)
+                .and_seconds(-1)
+                .and_nanoseconds(-500_000_000)
+                .new()
+                .total_seconds(),
+            -86_401.5
+        );
+    }
+
+    #[test]
+    fn test_signed_months() {
+        let rddt = RelativeDelta::with_months(-13).new();
+        assert_eq!(rddt.months(), -1);
+        assert_eq!(rddt.years(), -1);
+        assert_eq!(rddt.signed_months(), -13);
+        assert_eq!(
+            RelativeDelta::with_years(2)
+                .and_months(-1)
+                .new()
+                .signed_months(),
+            23
+        );
+    }
+
+    #[test]
+    fn test_abs() {
+        let positive = RelativeDelta::with_years(2).and_months(3).new();
+        assert_eq!((-positive).abs(), positive);
+        assert_eq!(positive.abs(), positive);
+
+        let rddt = RelativeDelta::with_days(-3)
+            .and_hours(-4)
+            .and_nanoseconds(-5)
+            .and_day(Some(1))
+            .and_weekday(Some((Weekday::Sun, -1)))
+            .new();
+        assert_eq!(
+            rddt.abs(),
+            RelativeDelta::with_days(3)
+                .and_hours(4)
+                .and_nanoseconds(5)
+                .and_day(Some(1))
+                .and_weekday(Some((Weekday::Sun, -1)))
+                .new()
+        );
+        // Mixed signs are made positive one by one
+        assert_eq!(
+            RelativeDelta::with_days(1).and_hours(-1).new().abs(),
+            RelativeDelta::with_days(1).and_hours(1).new()
+        );
+    }
+
+    #[test]
+    fn test_date_time_ext() {
+        use crate::DateTimeExt;
+
+        let dt = Utc.with_ymd_and_hms(2021, 1, 31, 12, 0, 0).unwrap();
+        let month = RelativeDelta::with_months(1).new();
+        assert_eq!(dt.plus_delta(month), dt + month);
+        assert_eq!((&dt).minus_delta(month), dt - month);
+        assert_eq!(
+            dt.plus_delta(month)
+                .plus_delta(RelativeDelta::with_day(1).new())
+                .minus_delta(RelativeDelta::with_hours(12).new()),
+            Utc.with_ymd_and_hms(2021, 2, 1, 0, 0, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_is_negative_and_signum() {
+        let positive = RelativeDelta::with_months(2).and_hours(3).new();
+        assert_eq!(positive.signum(), 1);
+        assert!(!positive.is_negative());
+        assert_eq!((-positive).signum(), -1);
+        assert!((-positive).is_negative());
+
+        let mixed = RelativeDelta::with_years(1).and_days(-400).new();
+        assert!(mixed.is_negative());
+        assert_eq!(
+            RelativeDelta::with_years(1).and_days(-365).new().signum(),
+            1
+        );
+        // A year of 365.2425 days cancels out exactly
+        let cancelled = RelativeDelta::with_years(1)
+            .and_days(-365)
+            .and_hours(-5)
+            .and_minutes(-49)
+            .and_seconds(-12)
+            .new();
+        assert_eq!(cancelled.signum(), 0);
+        assert!(!cancelled.is_negative());
+        assert_eq!(
+            RelativeDelta::with_years(1)
+                .and_days(-365)
+                .and_hours(-5)
+                .and_minutes(-49)
+                .and_seconds(-12)
+                .and_nanoseconds(-1)
+                .new()
+                .signum(),
+            -1
+        );
+        assert_eq!(RelativeDelta::with_day(1).new().signum(), 0);
+    }
+
+    #[test]
+    fn test_display_fractional_seconds() {
+        let rddt = |seconds, nanoseconds| {
+            RelativeDelta::with_seconds(seconds)
+                .and_nanoseconds(nanoseconds)
+                .new()
+        };
+        assert_eq!(rddt(1, 500_000_000).to_string(), "PT1.5S");
+        assert_eq!(rddt(0, 500_000_000).to_string(), "PT0.5S");
+        assert_eq!(rddt(0, 1).to_string(), "PT0.000000001S");
+        assert_eq!(rddt(2, 0).to_string(), "PT2S");
+        assert_eq!(rddt(0, 120_000_000).to_string(), "PT0.12S");
+        assert_eq!(rddt(0, 50_000_000).to_string(), "PT0.05S");
+        assert_eq!(rddt(-1, -500_000_000).to_string(), "-PT1.5S");
+    }
+
+    #[test]
+    fn test_try_into_chrono_duration() {
+        use crate::FromError;
+        use chrono::Duration;
+        use std::convert::TryFrom;
+
+        assert_eq!(
+            Duration::try_from(RelativeDelta::with_days(3).and_hours(2).new()),
+            Ok(Duration::hours(74))
+        );
+        assert_eq!(
+            Duration::try_from(RelativeDelta::with_seconds(-1).and_nanoseconds(-5).new()),
+            Ok(Duration::nanoseconds(-1_000_000_005))
+        );
+        assert_eq!(
+            Duration::try_from(RelativeDelta::default()),
+            Ok(Duration::zero())
+        );
+        assert_eq!(
+            Duration::try_from(RelativeDelta::with_months(1).new()),
+            Err(FromError::NonExactDuration)
+        );
+        assert_eq!(
+            Duration::try_from(RelativeDelta::with_days(1).and_day(Some(1)).new()),
+            Err(FromError::NonExactDuration)
+        );
+        assert_eq!(
+            Duration::try_from(RelativeDelta::with_weekday(Weekday::Mon, 1).new()),
+            Err(FromError::NonExactDuration)
+        );
+        assert!(Duration::try_from(RelativeDelta::with_days(i64::MAX).new()).is_err());
+    }
+
+    #[test]
+    fn test_last_nth_weekday() {
+        // dateutil: date(2021, 7, 14) + relativedelta(day=31, weekday=MO(-1)) == date(2021, 7, 26)
+        let dt = Utc.with_ymd_and_hms(2021, 7, 14, 0, 0, 0).unwrap();
+        let last_monday = RelativeDelta::last_nth_weekday(Weekday::Mon, 1)
+            .and_day(Some(31))
+            .new();
+        assert_eq!(
+            dt + last_monday,
+            Utc.with_ymd_and_hms(2021, 7, 26, 0, 0, 0).unwrap()
+        );
+        // dateutil: date(2021, 7, 14) + relativedelta(day=31, weekday=MO(-2)) == date(2021, 7, 19)
+        let second_to_last = RelativeDelta::last_nth_weekday(Weekday::Mon, 2)
+            .and_day(Some(31))
+            .new();
+        assert_eq!(
+            dt + second_to_last,
+            Utc.with_ymd_and_hms(2021, 7, 19, 0, 0, 0).unwrap()
+        );
+        // dateutil: date(2021, 2, 10) + relativedelta(day=31, weekday=MO(-1)) == date(2021, 2, 22)
+        let dt = Utc.with_ymd_and_hms(2021, 2, 10, 0, 0, 0).unwrap();
+        assert_eq!(
+            dt + last_monday,
+            Utc.with_ymd_and_hms(2021, 2, 22, 0, 0, 0).unwrap()
+        );
+        // Without a day, counting back from the date itself
+        assert_eq!(
+            dt + RelativeDelta::last_nth_weekday(Weekday::Wed, 1).new(),
+            Utc.with_ymd_and_hms(2021, 2, 10, 0, 0, 0).unwrap()
+        );
+        assert_eq!(
+            last_monday,
+            RelativeDelta::with_day(31)
+                .and_weekday(Some((Weekday::Mon, -1)))
+                .new()
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "nth last weekday 0 out of range 1..")]
+    fn test_last_nth_weekday_zero() {
+        RelativeDelta::last_nth_weekday(Weekday::Mon, 0);
+    }
+
+    #[test]
+    fn test_try_build_out_of_range() {
+        use crate::BuildError;
+
+        assert_eq!(
+            RelativeDelta::with_month(13).try_build(),
+            Err(BuildError::MonthOutOfRange(13))
+        );
+        assert_eq!(
+            RelativeDelta::with_day(32).try_build(),
+            Err(BuildError::DayOutOfRange(32))
+        );
+        assert_eq!(
+            RelativeDelta::with_hour(24).try_build(),
+            Err(BuildError::HourOutOfRange(24))
+        );
+        assert_eq!(
+            RelativeDelta::with_second(60).try_build(),
+            Err(BuildError::SecondOutOfRange(60))
+        );
+        assert_eq!(
+            RelativeDelta::with_nanosecond(1_000_000_000).try_build(),
+            Err(BuildError::NanosecondOutOfRange(1_000_000_000))
+        );
+        assert_eq!(
+            BuildError::DayOutOfRange(32).to_string(),
+            "day 32 out of range 1..=31"
+        );
+        assert_eq!(
+            RelativeDelta::with_month(12).and_hours(25).try_build(),
+            Ok(RelativeDelta::with_month(12).and_hours(25).new())
+        );
+    }
+
+    #[test]
+    fn test_compose_apply() {
+        let dt = Utc.with_ymd_and_hms(2020, 1, 31, 0, 0, 0).unwrap();
+        let month = RelativeDelta::with_months(1).new();
+        let monday = RelativeDelta::with_weekday(Weekday::Mon, 1).new();
+
+        assert_eq!(
+            RelativeDelta::compose_apply(dt, &[month, monday]),
+            Utc.with_ymd_and_hms(2020, 3, 2, 0, 0, 0).unwrap()
+        );
+        assert_eq!(
+            RelativeDelta::compose_apply(dt, &[monday, month]),
+            Utc.with_ymd_and_hms(2020, 3, 3, 0, 0, 0).unwrap()
+        );
+        assert_eq!(RelativeDelta::compose_apply(dt, &[]), dt);
+    }
+
+    #[test]
+    fn test_and_nanosecond_checked() {
+        use crate::BuildError;
+
+        let rd = RelativeDelta::with_seconds(1)
+            .and_nanosecond_checked(Some(999_999_999))
+            .unwrap()
+            .new();
+        assert_eq!(
+            rd,
+            RelativeDelta::with_seconds(1)
+                .and_nanosecond(Some(999_999_999))
+                .new()
+        );
+        assert_eq!(
+            RelativeDelta::with_seconds(1)
+                .and_nanosecond_checked(None)
+                .map(|b| b.new()),
+            Ok(RelativeDelta::with_seconds(1).new())
+        );
+        assert_eq!(
+            RelativeDelta::with_seconds(1)
+                .and_nanosecond_checked(Some(1_000_000_000))
+                .err(),
+            Some(BuildError::NanosecondOutOfRange(1_000_000_000))
+        );
+        assert_eq!(
+            RelativeDelta::with_seconds(1)
+                .and_nanosecond_checked(Some(u32::MAX))
+                .err(),
+            Some(BuildError::NanosecondOutOfRange(u32::MAX))
+        );
+    }
+
+    #[test]
+    fn test_day_overflow() {
+        use crate::DayOverflow;
+
+        let leap = Utc.with_ymd_and_hms(2020, 2, 10, 12, 0, 0).unwrap();
+        let common = Utc.with_ymd_and_hms(2021, 2, 10, 12, 0, 0).unwrap();
+
+        let clamp = RelativeDelta::with_day(31).new();
+        assert_eq!(clamp.day_overflow(), DayOverflow::Clamp);
+        assert_eq!(
+            leap + clamp,
+            Utc.with_ymd_and_hms(2020, 2, 29, 12, 0, 0).unwrap()
+        );
+        assert_eq!(
+            common + clamp,
+            Utc.with_ymd_and_hms(2021, 2, 28, 12, 0, 0).unwrap()
+        );
+
+        let rollover = RelativeDelta::with_day(31)
+            .and_day_overflow(DayOverflow::Rollover)
+            .new();
+        assert_eq!(
+            leap + rollover,
+            Utc.with_ymd_and_hms(2020, 3, 2, 12, 0, 0).unwrap()
+        );
+        assert_eq!(
+            common + rollover,
+            Utc.with_ymd_and_hms(2021, 3, 3, 12, 0, 0).unwrap()
+        );
+        // Also applies to the day kept from the date added to
+        let month = RelativeDelta::with_months(1)
+            .and_day_overflow(DayOverflow::Rollover)
+            .new();
+        assert_eq!(
+            Utc.with_ymd_and_hms(2021, 1, 31, 0, 0, 0).unwrap() + month,
+            Utc.with_ymd_and_hms(2021, 3, 3, 0, 0, 0).unwrap()
+        );
+
+        let error = RelativeDelta::with_day(31)
+            .and_day_overflow(DayOverflow::Error)
+            .new();
+        assert_eq!(error.checked_add_to(&leap), None);
+        assert_eq!(error.checked_add_to(&common), None);
+        assert_eq!(
+            error.checked_add_to(&Utc.with_ymd_and_hms(2021, 3, 10, 12, 0, 0).unwrap()),
+            Some(Utc.with_ymd_and_hms(2021, 3, 31, 12, 0, 0).unwrap())
+        );
+
+        assert_eq!(rollover.to_string(), "PT0S[day=31, day_overflow=Rollover]");
+        assert_eq!(rollover.to_string().parse::<RelativeDelta>(), Ok(rollover));
+    }
+
+    #[test]
+    fn test_iter_from() {
+        let start = Utc.with_ymd_and_hms(2020, 1, 31, 0, 0, 0).unwrap();
+        let monthly = RelativeDelta::with_months(1).new();
+
+        let dates: Vec<_> = monthly.iter_from(start).take(4).collect();
+        assert_eq!(
+            dates,
+            vec![
+                start,
+                Utc.with_ymd_and_hms(2020, 2, 29, 0, 0, 0).unwrap(),
+                Utc.with_ymd_and_hms(2020, 3, 29, 0, 0, 0).unwrap(),
+                Utc.with_ymd_and_hms(2020, 4, 29, 0, 0, 0).unwrap(),
+            ]
+        );
+        // Chained addition, not multiplication
+        assert_ne!(dates[2], start + monthly * 2);
+
+        let weekly = RelativeDelta::with_weeks(1).new();
+        assert_eq!(
+            weekly.iter_from(start).nth(52),
+            Some(Utc.with_ymd_and_hms(2021, 1, 29, 0, 0, 0).unwrap())
+        );
+    }
+
+    #[test]
+    fn test_range() {
+        use crate::range;
+
+        let start = Utc.with_ymd_and_hms(2021, 1, 1, 0, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2022, 1, 1, 0, 0, 0).unwrap();
+        let firsts: Vec<_> = range(start, end, RelativeDelta::with_months(1).new()).collect();
+        assert_eq!(firsts.len(), 12);
+        assert_eq!(firsts[0], start);
+        assert_eq!(
+            firsts[11],
+            Utc.with_ymd_and_hms(2021, 12, 1, 0, 0, 0).unwrap()
+        );
+
+        let backwards: Vec<_> = range(end, start, RelativeDelta::with_months(-1).new()).collect();
+        assert_eq!(backwards.len(), 12);
+        assert_eq!(backwards[0], end);
+        assert_eq!(
+            backwards[11],
+            Utc.with_ymd_and_hms(2021, 2, 1, 0, 0, 0).unwrap()
+        );
+
+        // Stepping away from end or an empty step yields nothing, a constant stops once it stalls
+        assert_eq!(
+            range(end, start, RelativeDelta::with_months(1).new()).count(),
+            0
+        );
+        assert_eq!(range(start, end, RelativeDelta::default()).count(), 0);
+        assert_eq!(
+            range(start, end, RelativeDelta::with_month(3).new()).count(),
+            2
+        );
+    }
+
+    #[test]
+    fn test_to_fields_from_parts() {
+        use crate::{Anchor, DayOverflow, RelativeDeltaFields};
+
+        let rd = RelativeDelta::with_years(2)
+            .and_months(-3)
+            .and_days(12)
+            .and_hours(5)
+            .and_nanoseconds(7)
+            .and_day(Some(31))
+            .and_weekday(Some((Weekday::Fri, -1)))
+            .and_weekday_overrides_day(false)
+            .and_weekday_relative_to(Anchor::BeforeDays)
+            .and_day_overflow(DayOverflow::Rollover)
+            .new();
+        let fields = rd.to_fields();
+        assert_eq!(fields.years, 2);
+        assert_eq!(fields.months, -3);
+        assert!(!fields.weekday_overrides_day);
+        assert_eq!(RelativeDelta::from_parts(fields), rd);
+
+        assert_eq!(
+            RelativeDeltaFields::default(),
+            RelativeDelta::default().to_fields()
+        );
+        let parts = RelativeDeltaFields {
+            months: 14,
+            hours: 25,
+            ..Default::default()
+        };
+        assert_eq!(
+            RelativeDelta::from_parts(parts),
+            RelativeDelta::with_years(1)
+                .and_months(2)
+                .and_days(1)
+                .and_hours(1)
+                .new()
+        );
+    }
+
+    #[test]
+    fn test_next_and_previous_weekday_from() {
+        // 2021-08-06 is a friday
+        let friday = Utc.with_ymd_and_hms(2021, 8, 6, 9, 30, 0).unwrap();
+        let monday = Utc.with_ymd_and_hms(2021, 8, 9, 9, 30, 0).unwrap();
+
+        assert_eq!(
+            RelativeDelta::next_weekday_from(&friday, Weekday::Fri),
+            friday
+        );
+        assert_eq!(
+            RelativeDelta::next_weekday_from(&monday, Weekday::Fri),
+            Utc.with_ymd_and_hms(2021, 8, 13, 9, 30, 0).unwrap()
+        );
+        assert_eq!(
+            RelativeDelta::previous_weekday_from(&friday, Weekday::Fri),
+            friday
+        );
+        assert_eq!(
+            RelativeDelta::previous_weekday_from(&monday, Weekday::Fri),
+            friday
+        );
+        assert_eq!(
+            RelativeDelta::next_weekday_from(&monday, Weekday::Fri),
+            monday + RelativeDelta::with_weekday(Weekday::Fri, 1).new()
+        );
+    }
+
+    #[test]
+    fn test_clamp_for_backend() {
+        use chrono::Datelike;
+
+        let dt = Utc.with_ymd_and_hms(2020, 6, 15, 0, 0, 0).unwrap();
+        let (min, max) = RelativeDelta::backend_year_bounds();
+
+        let up = RelativeDelta::with_years(i32::MAX).and_days(1).new();
+        assert_eq!(up.checked_add_to(&dt), None);
+        let clamped = up.clamp_for_backend(&dt);
+        assert_eq!(clamped.years() as i64, max - 2020);
+        assert_eq!(clamped.days(), 1);
+        assert_eq!(
+            clamped
+                .checked_add_to(&dt)
+                .map(|d| (d.year() as i64, d.month(), d.day())),
+            Some((max, 6, 16))
+        );
+
+        let down = RelativeDelta::with_years(i32::MIN).new();
+        assert_eq!(
+            down.clamp_for_backend(&dt)
+                .checked_add_to(&dt)
+                .map(|d| d.year() as i64),
+            Some(min)
+        );
+
+        // Months carrying into years are taken into account
+        let months = RelativeDelta::with_years(i32::MAX).and_months(11).new();
+        let clamped = months.clamp_for_backend(&dt);
+        assert_eq!(clamped.years() as i64, max - 2021);
+        assert!(clamped.checked_add_to(&dt).is_some());
+
+        let within = RelativeDelta::with_years(100).and_month(Some(2)).new();
+        assert_eq!(within.clamp_for_backend(&dt), within);
+    }
+
+    #[test]
+    fn test_checked_from_chrono_diff() {
+        let earlier = Utc.with_ymd_and_hms(2020, 1, 31, 12, 0, 0).unwrap();
+        let later = Utc.with_ymd_and_hms(2020, 3, 1, 13, 30, 0).unwrap();
+        let rddt = RelativeDelta::checked_from_chrono_diff(&later, &earlier).unwrap();
+        assert_eq!(
+            rddt,
+            RelativeDelta::with_months(1)
+                .and_days(1)
+                .and_hours(1)
+                .and_minutes(30)
+                .new()
+        );
+        assert_eq!(earlier + rddt, later);
+
+        let earlier = Utc.with_ymd_and_hms(-200_000, 2, 29, 0, 0, 0).unwrap();
+        let later = Utc.with_ymd_and_hms(200_000, 7, 15, 6, 0, 0).unwrap();
+        let rddt = RelativeDelta::checked_from_chrono_diff(&later, &earlier).unwrap();
+        assert_eq!(rddt.years(), 400_000);
+        assert_eq!(earlier + rddt, later);
+        let rddt = RelativeDelta::checked_from_chrono_diff(&earlier, &later).unwrap();
+        assert_eq!(rddt.years(), -400_000);
+        assert_eq!(later + rddt, earlier);
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn test_time_months_between() {
+        use std::convert::TryFrom;
+
+        let datetime = |year, month, day, hour, minute| {
+            time::Date::from_calendar_date(year, time::Month::try_from(month).unwrap(), day)
+                .unwrap()
+                .with_hms(hour, minute, 0)
+                .unwrap()
+        };
+
+        let earlier = datetime(2020, 1, 31, 12, 0);
+        assert_eq!(
+            RelativeDelta::time_months_between(&datetime(2020, 3, 1, 13, 30), &earlier),
+            1
+        );
+        // Clamped to the end of february
+        assert_eq!(
+            RelativeDelta::time_months_between(&datetime(2020, 2, 29, 12, 0), &earlier),
+            1
+        );
+        assert_eq!(
+            RelativeDelta::time_months_between(&datetime(2020, 2, 29, 11, 59), &earlier),
+            0
+        );
+        assert_eq!(
+            RelativeDelta::time_months_between(&datetime(2021, 4, 30, 12, 0), &earlier),
+            15
+        );
+        assert_eq!(
+            RelativeDelta::time_months_between(&earlier, &datetime(2021, 4, 30, 12, 0)),
+            -14
+        );
+    }
+
+    #[test]
+    fn test_between() {
+        // Month length mismatch, as in dateutil
+        let earlier = Utc.with_ymd_and_hms(2021, 1, 31, 0, 0, 0).unwrap();
+        let later = Utc.with_ymd_and_hms(2021, 3, 1, 0, 0, 0).unwrap();
+        let rddt = RelativeDelta::between(&later, &earlier);
+        assert_eq!(rddt, RelativeDelta::with_months(1).and_days(1).new());
+        assert_eq!(
+            RelativeDelta::between(&earlier, &later),
+            RelativeDelta::with_months(-1).and_days(-1).new()
+        );
+
+        // Leap day endpoints
+        let leap_day = Utc.with_ymd_and_hms(2020, 2, 29, 0, 0, 0).unwrap();
+        let next_year = Utc.with_ymd_and_hms(2021, 2, 28, 0, 0, 0).unwrap();
+        assert_eq!(
+            RelativeDelta::between(&next_year, &leap_day),
+            RelativeDelta::with_years(1).new()
+        );
+        assert_eq!(
+            RelativeDelta::between(&leap_day, &next_year),
+            RelativeDelta::with_months(-11).and_days(-28).new()
+        );
+
+        // Round trip pseudo random pairs, both directions
+        let mut seed: u64 = 0x2545_f491_4f6c_dd1d;
+        let mut random = |bound: u64| {
+            seed = seed
+                .wrapping_mul(6_364_136_223_846_793_005)
+                .wrapping_add(1_442_695_040_888_963_407);
+            (seed >> 33) % bound
+        };
+        for _ in 0..1000 {
+            let mut datetime = || {
+                let year = 1900 + random(200) as i32;
+                let month = 1 + random(12) as u32;
+                let day =
+                    1 + random(crate::relativedelta::num_days_in_month(year, month) as u64) as u32;
+                Utc.with_ymd_and_hms(
+                    year,
+                    month,
+                    day,
+                    random(24) as u32,
+                    random(60) as u32,
+                    random(60) as u32,
+                )
+                .unwrap()
+            };
+            let (a, b) = (datetime(), datetime());
+            assert_eq!(b + RelativeDelta::between(&a, &b), a, "{} - {}", a, b);
+            assert_eq!(a + RelativeDelta::between(&b, &a), b, "{} - {}", b, a);
+        }
+    }
+
+    #[test]
+    fn test_checked_add_weekday_in_month() {
+        // February 2021 starts on a monday and only holds four of them
+        let dt = Utc.with_ymd_and_hms(2021, 2, 10, 0, 0, 0).unwrap();
+        let first = RelativeDelta::with_day(1)
+            .and_weekday(Some((Weekday::Mon, 1)))
+            .new();
+        assert_eq!(
+            first.checked_add_weekday_in_month(&dt),
+            Some(Utc.with_ymd_and_hms(2021, 2, 1, 0, 0, 0).unwrap())
+        );
+
+        let fourth = RelativeDelta::with_day(1)
+            .and_weekday(Some((Weekday::Mon, 4)))
+            .new();
+        assert_eq!(
+            fourth.checked_add_weekday_in_month(&dt),
+            Some(Utc.with_ymd_and_hms(2021, 2, 22, 0, 0, 0).unwrap())
+        );
+
+        let fifth = RelativeDelta::with_day(1)
+            .and_weekday(Some((Weekday::Mon, 5)))
+            .new();
+        assert_eq!(fifth.checked_add_weekday_in_month(&dt), None);
+        // The operator overflows into the following month
+        assert_eq!(
+            dt + fifth,
+            Utc.with_ymd_and_hms(2021, 3, 1, 0, 0, 0).unwrap()
+        );
+
+        // Out of range results are None rather than a panic
+        use chrono::Datelike;
+        let max = DateTime::<Utc>::MAX_UTC;
+        assert_eq!(
+            RelativeDelta::with_years(1)
+                .new()
+                .checked_add_weekday_in_month(&max),
+            None
+        );
+        let next = RelativeDelta::with_weekday(max.weekday().succ(), 2).new();
+        assert_eq!(next.checked_add_weekday_in_month(&max), None);
+    }
 }
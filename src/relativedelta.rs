@@ -2,9 +2,10 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
-use chrono::{Datelike, Months, Timelike};
+use chrono::{Datelike, Timelike};
 use num_integer::Integer;
 use num_traits::Zero;
+use std::convert::TryFrom;
 use std::ops;
 use std::ops::Add;
 
@@ -26,10 +27,20 @@ pub struct Builder {
     months: i64,
     months_f: f64,
     days: i64,
+    weeks: i64,
     hours: i64,
     minutes: i64,
     seconds: i64,
     nanoseconds: i64,
+    // Fractional parts of the units set from floats, distributed into the smaller units on creation
+    weeks_f: f64,
+    days_f: f64,
+    hours_f: f64,
+    minutes_f: f64,
+    seconds_f: f64,
+    // Sub-second units, added to the seconds and nanoseconds on creation
+    milliseconds: i64,
+    microseconds: i64,
     year: Option<i32>,
     month: Option<u32>,
     day: Option<u32>,
@@ -38,23 +49,93 @@ pub struct Builder {
     minute: Option<u32>,
     second: Option<u32>,
     nanosecond: Option<u32>,
+    week_start: WeekStart,
+    day_shift: Option<DayShift>,
+    pin_day: bool,
+    iso_week: Option<(chrono::Weekday, u8)>,
+    weekday_anchor: Anchor,
+    day_overflow: DayOverflow,
+    keep_months: bool,
+    set: u32,
 }
 
+// Bits tracking which parameters have explicitly been set on a Builder
+const SET_YEARS: u32 = 1 << 0;
+const SET_MONTHS: u32 = 1 << 1;
+const SET_MONTHS_F: u32 = 1 << 2;
+const SET_DAYS: u32 = 1 << 3;
+const SET_HOURS: u32 = 1 << 4;
+const SET_MINUTES: u32 = 1 << 5;
+const SET_SECONDS: u32 = 1 << 6;
+const SET_NANOSECONDS: u32 = 1 << 7;
+const SET_YEAR: u32 = 1 << 8;
+const SET_MONTH: u32 = 1 << 9;
+const SET_DAY: u32 = 1 << 10;
+const SET_WEEKDAY: u32 = 1 << 11;
+const SET_HOUR: u32 = 1 << 12;
+const SET_MINUTE: u32 = 1 << 13;
+const SET_SECOND: u32 = 1 << 14;
+const SET_NANOSECOND: u32 = 1 << 15;
+const SET_WEEK_START: u32 = 1 << 16;
+const SET_DAY_SHIFT: u32 = 1 << 17;
+const SET_PIN_DAY: u32 = 1 << 18;
+const SET_ISO_WEEK: u32 = 1 << 19;
+const SET_WEEKDAY_ANCHOR: u32 = 1 << 20;
+const SET_WEEKS: u32 = 1 << 21;
+const SET_DAY_OVERFLOW: u32 = 1 << 22;
+const SET_MILLISECONDS: u32 = 1 << 23;
+const SET_MICROSECONDS: u32 = 1 << 24;
+
 impl Builder {
     /// Construct new RelativeDelta
     ///
     /// Returns a fixed RelativeDelta where time parameters are within meaningfull boundaries.
+    ///
+    /// Panics if a constant parameter is out of range, see [`Builder::try_build`] for a fallible alternative.
     #[inline]
     pub fn new(&self) -> RelativeDelta {
+        self.try_build().unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Construct new RelativeDelta, or an error if a constant parameter is out of range
+    ///
+    /// E.g. a month of 13 gives `BuildError::MonthOutOfRange(13)` where [`Builder::new`] would panic.
+    #[inline]
+    pub fn try_build(&self) -> Result<RelativeDelta, BuildError> {
+        // Fractions are only distributed here, so the result does not depend on the order the setters were called in
+        let carry = Self::normalize(
+            0.0,
+            0.0,
+            self.weeks_f * 7_f64 + self.days_f,
+            self.hours_f,
+            self.minutes_f,
+            0.0,
+            0,
+        );
+        let (_, seconds_nanos) = split_seconds_f(self.seconds_f);
+        // Whole seconds are split off the sub-second units first, and carried into the minutes where they would
+        // overflow the seconds
+        let seconds = self.seconds as i128
+            + carry.seconds as i128
+            + (self.milliseconds / 1_000) as i128
+            + (self.microseconds / 1_000_000) as i128;
+        let (overflow_minutes, seconds) = match i64::try_from(seconds) {
+            Ok(seconds) => (0, seconds),
+            Err(_) => ((seconds / 60) as i64, (seconds % 60) as i64),
+        };
         let mut ddt = RelativeDelta {
             years: self.years,
             months: self.months,
             months_f: self.months_f,
-            days: self.days,
-            hours: self.hours,
-            minutes: self.minutes,
-            seconds: self.seconds,
-            nanoseconds: self.nanoseconds,
+            days: self.days + self.weeks * 7 + carry.days,
+            hours: self.hours + carry.hours,
+            minutes: self.minutes + carry.minutes + overflow_minutes,
+            seconds,
+            nanoseconds: self.nanoseconds
+                + carry.nanoseconds
+                + seconds_nanos
+                + self.milliseconds % 1_000 * 1_000_000
+                + self.microseconds % 1_000_000 * 1_000,
             year: self.year,
             month: self.month,
             day: self.day,
@@ -63,9 +144,18 @@ impl Builder {
             minute: self.minute,
             second: self.second,
             nanosecond: self.nanosecond,
+            week_start: self.week_start,
+            day_shift: self.day_shift,
+            pin_day: self.pin_day,
+            iso_week: self.iso_week,
+            weekday_anchor: self.weekday_anchor,
+            day_overflow: self.day_overflow,
         };
-        Self::fix(&mut ddt);
-        ddt
+        if let Some(err) = Self::out_of_range(&ddt) {
+            return Err(err);
+        }
+        Self::fix(&mut ddt, self.keep_months);
+        Ok(ddt)
     }
 
     #[inline]
@@ -84,6 +174,8 @@ impl Builder {
         self.months = months;
         self.day = day;
         self.days = days;
+        self.days_f = 0.0;
+        self.set |= SET_YEAR | SET_YEARS | SET_MONTH | SET_MONTHS | SET_DAY | SET_DAYS;
         self
     }
 
@@ -99,10 +191,14 @@ impl Builder {
     ) -> &mut Self {
         self.hour = hour;
         self.hours = hours;
+        self.hours_f = 0.0;
         self.minute = minute;
         self.minutes = minutes;
+        self.minutes_f = 0.0;
         self.second = second;
         self.seconds = seconds;
+        self.seconds_f = 0.0;
+        self.set |= SET_HOUR | SET_HOURS | SET_MINUTE | SET_MINUTES | SET_SECOND | SET_SECONDS;
         self
     }
 
@@ -110,31 +206,65 @@ impl Builder {
     /// Clone the builder and set years
     #[inline]
     pub fn with_years(&self, years: i32) -> Self {
-        Self { years, ..*self }
+        Self {
+            years,
+            set: self.set | SET_YEARS,
+            ..*self
+        }
     }
 
     /// Clone the builder and set months
     #[inline]
     pub fn with_months(&self, months: i64) -> Self {
-        Self { months, ..*self }
+        Self {
+            months,
+            set: self.set | SET_MONTHS,
+            ..*self
+        }
     }
 
     /// Clone the builder and set days
     #[inline]
     pub fn with_days(&self, days: i64) -> Self {
-        Self { days, ..*self }
+        Self {
+            days,
+            days_f: 0.0,
+            set: self.set | SET_DAYS,
+            ..*self
+        }
+    }
+
+    /// Clone the builder and set weeks, added as 7 days each to the days on creation
+    #[inline]
+    pub fn with_weeks(&self, weeks: i64) -> Self {
+        Self {
+            weeks,
+            weeks_f: 0.0,
+            set: self.set | SET_WEEKS,
+            ..*self
+        }
     }
 
     /// Clone the builder and set hours
     #[inline]
     pub fn with_hours(&self, hours: i64) -> Self {
-        Self { hours, ..*self }
+        Self {
+            hours,
+            hours_f: 0.0,
+            set: self.set | SET_HOURS,
+            ..*self
+        }
     }
 
     /// Clone the builder and set minutes
     #[inline]
     pub fn with_minutes(&self, minutes: i64) -> Self {
-        Self { minutes, ..*self }
+        Self {
+            minutes,
+            minutes_f: 0.0,
+            set: self.set | SET_MINUTES,
+            ..*self
+        }
     }
 
     /// Clone the builder and set nanoseconds
@@ -142,6 +272,7 @@ impl Builder {
     pub fn with_nanoseconds(&self, nanoseconds: i64) -> Self {
         Self {
             nanoseconds,
+            set: self.set | SET_NANOSECONDS,
             ..*self
         }
     }
@@ -151,6 +282,7 @@ impl Builder {
     #[inline]
     pub fn and_years(&mut self, years: i32) -> &mut Self {
         self.years = years;
+        self.set |= SET_YEARS;
         self
     }
 
@@ -158,6 +290,7 @@ impl Builder {
     #[inline]
     pub fn and_months(&mut self, months: i64) -> &mut Self {
         self.months = months;
+        self.set |= SET_MONTHS;
         self
     }
 
@@ -165,6 +298,7 @@ impl Builder {
     #[inline]
     pub fn and_months_f(&mut self, months_f: f64) -> &mut Self {
         self.months_f = months_f;
+        self.set |= SET_MONTHS_F;
         self
     }
 
@@ -172,6 +306,19 @@ impl Builder {
     #[inline]
     pub fn and_days(&mut self, days: i64) -> &mut Self {
         self.days = days;
+        self.days_f = 0.0;
+        self.set |= SET_DAYS;
+        self
+    }
+
+    /// Set weeks on mutable ref and return itself for further chaining
+    ///
+    /// Weeks are added as 7 days each to the days on creation, so they compose with `and_days` in either order.
+    #[inline]
+    pub fn and_weeks(&mut self, weeks: i64) -> &mut Self {
+        self.weeks = weeks;
+        self.weeks_f = 0.0;
+        self.set |= SET_WEEKS;
         self
     }
 
@@ -179,6 +326,45 @@ impl Builder {
     #[inline]
     pub fn and_hours(&mut self, hours: i64) -> &mut Self {
         self.hours = hours;
+        self.hours_f = 0.0;
+        self.set |= SET_HOURS;
+        self
+    }
+
+    /// Set weeks from fractional weeks on mutable ref and return itself for further chaining
+    ///
+    /// Replaces the weeks, whole or fractional, set before. Whole weeks are added as 7 days each like `and_weeks`, and
+    /// the fractional week is distributed into days, hours, minutes, seconds and nanoseconds on creation, on top of
+    /// those set separately.
+    #[inline]
+    pub fn and_weeks_f(&mut self, weeks: f64) -> &mut Self {
+        self.weeks = weeks.trunc() as i64;
+        self.weeks_f = weeks.fract();
+        self.set |= SET_WEEKS;
+        self
+    }
+
+    /// Set days from fractional days on mutable ref and return itself for further chaining
+    ///
+    /// Replaces the days, whole or fractional, set before. A day is taken as a fixed 24 hours, and the fractional part
+    /// is distributed into hours, minutes, seconds and nanoseconds on creation, on top of those set separately.
+    #[inline]
+    pub fn and_days_f(&mut self, days: f64) -> &mut Self {
+        self.days = days.trunc() as i64;
+        self.days_f = days.fract();
+        self.set |= SET_DAYS;
+        self
+    }
+
+    /// Set hours from fractional hours on mutable ref and return itself for further chaining
+    ///
+    /// Replaces the hours, whole or fractional, set before. The fractional part is distributed into minutes, seconds
+    /// and nanoseconds on creation, on top of those set separately.
+    #[inline]
+    pub fn and_hours_f(&mut self, hours: f64) -> &mut Self {
+        self.hours = hours.trunc() as i64;
+        self.hours_f = hours.fract();
+        self.set |= SET_HOURS;
         self
     }
 
@@ -186,6 +372,20 @@ impl Builder {
     #[inline]
     pub fn and_minutes(&mut self, minutes: i64) -> &mut Self {
         self.minutes = minutes;
+        self.minutes_f = 0.0;
+        self.set |= SET_MINUTES;
+        self
+    }
+
+    /// Set minutes from fractional minutes on mutable ref and return itself for further chaining
+    ///
+    /// Replaces the minutes, whole or fractional, set before. The fractional part is distributed into seconds and
+    /// nanoseconds on creation, on top of those set separately.
+    #[inline]
+    pub fn and_minutes_f(&mut self, minutes: f64) -> &mut Self {
+        self.minutes = minutes.trunc() as i64;
+        self.minutes_f = minutes.fract();
+        self.set |= SET_MINUTES;
         self
     }
 
@@ -193,6 +393,32 @@ impl Builder {
     #[inline]
     pub fn and_seconds(&mut self, seconds: i64) -> &mut Self {
         self.seconds = seconds;
+        self.seconds_f = 0.0;
+        self.set |= SET_SECONDS;
+        self
+    }
+
+    /// Set seconds from fractional seconds on mutable ref and return itself for further chaining
+    ///
+    /// Replaces the seconds, whole or fractional, set before. The fractional part is added to the nanoseconds on
+    /// creation, on top of those set separately.
+    #[inline]
+    pub fn and_seconds_f(&mut self, seconds: f64) -> &mut Self {
+        self.seconds = seconds.trunc() as i64;
+        self.seconds_f = seconds.fract();
+        self.set |= SET_SECONDS;
+        self
+    }
+
+    /// Add the sub-second part of a std::time::Duration to nanoseconds on mutable ref and return itself for further
+    /// chaining
+    ///
+    /// Whole seconds of the duration are ignored, so e.g. a measured duration can be matched exactly together with
+    /// `and_seconds`.
+    #[inline]
+    pub fn and_subsec(&mut self, d: std::time::Duration) -> &mut Self {
+        self.nanoseconds += d.subsec_nanos() as i64;
+        self.set |= SET_NANOSECONDS;
         self
     }
 
@@ -200,6 +426,29 @@ impl Builder {
     #[inline]
     pub fn and_nanoseconds(&mut self, nanoseconds: i64) -> &mut Self {
         self.nanoseconds = nanoseconds;
+        self.set |= SET_NANOSECONDS;
+        self
+    }
+
+    /// Set milliseconds on mutable ref and return itself for further chaining
+    ///
+    /// Replaces the milliseconds set before. They are added to the seconds and nanoseconds on creation, on top of those
+    /// set separately, splitting off whole seconds first so the full range of i64 milliseconds is supported.
+    #[inline]
+    pub fn and_milliseconds(&mut self, milliseconds: i64) -> &mut Self {
+        self.milliseconds = milliseconds;
+        self.set |= SET_MILLISECONDS;
+        self
+    }
+
+    /// Set microseconds on mutable ref and return itself for further chaining
+    ///
+    /// Replaces the microseconds set before. They are added to the seconds and nanoseconds on creation, on top of those
+    /// set separately, splitting off whole seconds first so the full range of i64 microseconds is supported.
+    #[inline]
+    pub fn and_microseconds(&mut self, microseconds: i64) -> &mut Self {
+        self.microseconds = microseconds;
+        self.set |= SET_MICROSECONDS;
         self
     }
 
@@ -208,28 +457,44 @@ impl Builder {
     /// If year is set to None, addition with e.g. chrono::DateTime will just keep the DateTimes year and not overwrite it
     #[inline]
     pub fn with_year(self, year: Option<i32>) -> Self {
-        Self { year, ..self }
+        Self {
+            year,
+            set: self.set | SET_YEAR,
+            ..self
+        }
     }
 
     /// Clone the builder and set an optional month
     /// If month is set to None, addition with e.g. chrono::DateTime will just keep the DateTimes month and not overwrite it
     #[inline]
     pub fn with_month(self, month: Option<u32>) -> Self {
-        Self { month, ..self }
+        Self {
+            month,
+            set: self.set | SET_MONTH,
+            ..self
+        }
     }
 
     /// Clone the builder and set an optional day
     /// If day is set to None, addition with e.g. chrono::DateTime will just keep the DateTimes day and not overwrite it
     #[inline]
     pub fn with_day(self, day: Option<u32>) -> Self {
-        Self { day, ..self }
+        Self {
+            day,
+            set: self.set | SET_DAY,
+            ..self
+        }
     }
 
     /// Clone the builder and set an optional hour
     /// If hour is set to None, addition with e.g. chrono::DateTime will just keep the DateTimes hour and not overwrite it
     #[inline]
     pub fn with_hour(self, hour: Option<u32>) -> Self {
-        Self { hour, ..self }
+        Self {
+            hour,
+            set: self.set | SET_HOUR,
+            ..self
+        }
     }
 
     /// Set year on mutable ref and return itself for further chaining
@@ -237,6 +502,7 @@ impl Builder {
     #[inline]
     pub fn and_year(&mut self, year: Option<i32>) -> &mut Self {
         self.year = year;
+        self.set |= SET_YEAR;
         self
     }
 
@@ -245,14 +511,22 @@ impl Builder {
     #[inline]
     pub fn and_month(&mut self, month: Option<u32>) -> &mut Self {
         self.month = month;
+        self.set |= SET_MONTH;
         self
     }
 
+    /// Set month from a chrono::Month on mutable ref and return itself for further chaining
+    #[inline]
+    pub fn and_month_enum(&mut self, month: chrono::Month) -> &mut Self {
+        self.and_month(Some(month.number_from_month()))
+    }
+
     /// Set day on mutable ref and return itself for further chaining
     /// If day is set to None, addition with e.g. chrono::DateTime will just keep the DateTimes day and not overwrite it
     #[inline]
     pub fn and_day(&mut self, day: Option<u32>) -> &mut Self {
         self.day = day;
+        self.set |= SET_DAY;
         self
     }
 
@@ -261,6 +535,7 @@ impl Builder {
     #[inline]
     pub fn and_hour(&mut self, hour: Option<u32>) -> &mut Self {
         self.hour = hour;
+        self.set |= SET_HOUR;
         self
     }
 
@@ -269,6 +544,7 @@ impl Builder {
     #[inline]
     pub fn and_minute(&mut self, minute: Option<u32>) -> &mut Self {
         self.minute = minute;
+        self.set |= SET_MINUTE;
         self
     }
 
@@ -277,6 +553,7 @@ impl Builder {
     #[inline]
     pub fn and_second(&mut self, second: Option<u32>) -> &mut Self {
         self.second = second;
+        self.set |= SET_SECOND;
         self
     }
 
@@ -285,50 +562,253 @@ impl Builder {
     #[inline]
     pub fn and_nanosecond(&mut self, nanosecond: Option<u32>) -> &mut Self {
         self.nanosecond = nanosecond;
+        self.set |= SET_NANOSECOND;
         self
     }
 
+    /// Set nanosecond and return the builder, or an error if it is not within `0..=999_999_999`
+    ///
+    /// Validates when the parameter is set, instead of panicking once the RelativeDelta is constructed.
+    #[inline]
+    pub fn and_nanosecond_checked(mut self, nanosecond: Option<u32>) -> Result<Self, BuildError> {
+        if let Some(ns) = nanosecond.filter(|&ns| ns > 999_999_999) {
+            return Err(BuildError::NanosecondOutOfRange(ns));
+        }
+        self.and_nanosecond(nanosecond);
+        Ok(self)
+    }
+
     /// Set weekday on mutable ref and return itself for further chaining
     /// If weekday is set to None, addition with e.g. chrono::DateTime will just keep the DateTimes weekday and not overwrite it
     #[inline]
     pub fn and_weekday(&mut self, weekday_nth: Option<(chrono::Weekday, i64)>) -> &mut Self {
         self.weekday = weekday_nth;
+        self.set |= SET_WEEKDAY;
         self
     }
 
+    /// Set weekday and nth on mutable ref and return itself for further chaining
+    ///
+    /// Same as `and_weekday(Some((weekday, nth)))`.
     #[inline]
-    fn fix(ddt: &mut RelativeDelta) {
-        assert!(
-            ddt.month.map_or(true, |m| (1..=12).contains(&m)),
-            "invalid month {}",
-            ddt.month.unwrap()
-        );
-        assert!(
-            ddt.day.map_or(true, |d| (1..=31).contains(&d)),
-            "invalid day {}",
-            ddt.day.unwrap()
-        );
-        assert!(
-            ddt.hour.map_or(true, |h| (0..=23).contains(&h)),
-            "invalid hour {}",
-            ddt.hour.unwrap()
-        );
-        assert!(
-            ddt.minute.map_or(true, |m| (0..=59).contains(&m)),
-            "invalid minute {}",
-            ddt.minute.unwrap()
-        );
-        assert!(
-            ddt.second.map_or(true, |s| (0..=59).contains(&s)),
-            "invalid second {}",
-            ddt.second.unwrap()
-        );
-        assert!(
-            ddt.nanosecond
-                .map_or(true, |n| (0..=999_999_999).contains(&n)),
-            "invalid nanosecond {}",
-            ddt.nanosecond.unwrap()
-        );
+    pub fn and_weekday_parts(&mut self, weekday: chrono::Weekday, nth: i64) -> &mut Self {
+        self.and_weekday(Some((weekday, nth)))
+    }
+
+    /// Clear weekday on mutable ref and return itself for further chaining
+    ///
+    /// Same as `and_weekday(None)`.
+    #[inline]
+    pub fn and_no_weekday(&mut self) -> &mut Self {
+        self.and_weekday(None)
+    }
+
+    /// Set the first day of the week on mutable ref and return itself for further chaining
+    #[inline]
+    pub fn and_week_start(&mut self, week_start: WeekStart) -> &mut Self {
+        self.week_start = week_start;
+        self.set |= SET_WEEK_START;
+        self
+    }
+
+    /// Set whether the weekday may move off a constant day on mutable ref and return itself for further chaining
+    /// Defaults to true, jumping to the weekday after the constant day has been applied. When false, a set constant
+    /// day pins the date and the weekday is only applied when no constant day is given.
+    #[inline]
+    pub fn and_weekday_overrides_day(&mut self, weekday_overrides_day: bool) -> &mut Self {
+        self.pin_day = !weekday_overrides_day;
+        self.set |= SET_PIN_DAY;
+        self
+    }
+
+    /// Set the date the weekday jump is computed from on mutable ref and return itself for further chaining
+    /// Defaults to Anchor::AfterDays, computing the jump from the date after relative days and time are applied
+    #[inline]
+    pub fn and_weekday_relative_to(&mut self, anchor: Anchor) -> &mut Self {
+        self.weekday_anchor = anchor;
+        self.set |= SET_WEEKDAY_ANCHOR;
+        self
+    }
+
+    /// Set how a day past the end of the resolved month is handled on mutable ref and return itself for further
+    /// chaining
+    /// Defaults to DayOverflow::Clamp, moving e.g. a constant day 31 back to the last day of february
+    #[inline]
+    pub fn and_day_overflow(&mut self, day_overflow: DayOverflow) -> &mut Self {
+        self.day_overflow = day_overflow;
+        self.set |= SET_DAY_OVERFLOW;
+        self
+    }
+
+    /// Set weekday within an ISO week on mutable ref and return itself for further chaining
+    /// If set to None, addition with e.g. chrono::DateTime will not move the date into an ISO week
+    /// Panics if the week is not within 1..=53
+    #[inline]
+    pub fn and_iso_week(&mut self, weekday_week: Option<(chrono::Weekday, u8)>) -> &mut Self {
+        if let Some((_, week)) = weekday_week {
+            assert!(
+                (1..=53).contains(&week),
+                "ISO week {} out of range 1..=53",
+                week
+            );
+        }
+        self.iso_week = weekday_week;
+        self.set |= SET_ISO_WEEK;
+        self
+    }
+
+    /// Set day shift on mutable ref and return itself for further chaining
+    /// If day shift is set to None, addition with e.g. chrono::DateTime will not shift the resulting date
+    #[inline]
+    pub fn and_day_shift(&mut self, day_shift: Option<DayShift>) -> &mut Self {
+        self.day_shift = day_shift;
+        self.set |= SET_DAY_SHIFT;
+        self
+    }
+
+    /// Overlay the parameters explicitly set on another builder on top of this one
+    ///
+    /// Only parameters that have been set through a setter or convenience constructor on `other` are copied, so
+    /// parameters left at their default do not reset those of self. Useful for layering configurations.
+    pub fn overlay(self, other: &Builder) -> Self {
+        let mut merged = self;
+        if other.set & SET_YEARS != 0 {
+            merged.years = other.years;
+        }
+        if other.set & SET_MONTHS != 0 {
+            merged.months = other.months;
+        }
+        if other.set & SET_MONTHS_F != 0 {
+            merged.months_f = other.months_f;
+        }
+        if other.set & SET_DAYS != 0 {
+            merged.days = other.days;
+            merged.days_f = other.days_f;
+        }
+        if other.set & SET_WEEKS != 0 {
+            merged.weeks = other.weeks;
+            merged.weeks_f = other.weeks_f;
+        }
+        if other.set & SET_HOURS != 0 {
+            merged.hours = other.hours;
+            merged.hours_f = other.hours_f;
+        }
+        if other.set & SET_MINUTES != 0 {
+            merged.minutes = other.minutes;
+            merged.minutes_f = other.minutes_f;
+        }
+        if other.set & SET_SECONDS != 0 {
+            merged.seconds = other.seconds;
+            merged.seconds_f = other.seconds_f;
+        }
+        if other.set & SET_NANOSECONDS != 0 {
+            merged.nanoseconds = other.nanoseconds;
+        }
+        if other.set & SET_MILLISECONDS != 0 {
+            merged.milliseconds = other.milliseconds;
+        }
+        if other.set & SET_MICROSECONDS != 0 {
+            merged.microseconds = other.microseconds;
+        }
+        if other.set & SET_YEAR != 0 {
+            merged.year = other.year;
+        }
+        if other.set & SET_MONTH != 0 {
+            merged.month = other.month;
+        }
+        if other.set & SET_DAY != 0 {
+            merged.day = other.day;
+        }
+        if other.set & SET_WEEKDAY != 0 {
+            merged.weekday = other.weekday;
+        }
+        if other.set & SET_HOUR != 0 {
+            merged.hour = other.hour;
+        }
+        if other.set & SET_MINUTE != 0 {
+            merged.minute = other.minute;
+        }
+        if other.set & SET_SECOND != 0 {
+            merged.second = other.second;
+        }
+        if other.set & SET_NANOSECOND != 0 {
+            merged.nanosecond = other.nanosecond;
+        }
+        if other.set & SET_WEEK_START != 0 {
+            merged.week_start = other.week_start;
+        }
+        if other.set & SET_DAY_SHIFT != 0 {
+            merged.day_shift = other.day_shift;
+        }
+        if other.set & SET_PIN_DAY != 0 {
+            merged.pin_day = other.pin_day;
+        }
+        if other.set & SET_ISO_WEEK != 0 {
+            merged.iso_week = other.iso_week;
+        }
+        if other.set & SET_WEEKDAY_ANCHOR != 0 {
+            merged.weekday_anchor = other.weekday_anchor;
+        }
+        if other.set & SET_DAY_OVERFLOW != 0 {
+            merged.day_overflow = other.day_overflow;
+        }
+        merged.keep_months |= other.keep_months;
+        merged.set |= other.set;
+        merged
+    }
+
+    /// Keep months as given instead of aggregating them up into years on creation
+    ///
+    /// All other parameters are still normalized. Note that comparison is done field by field, so e.g. 18 months kept
+    /// as months will not equal 1 year and 6 months, even though `total_months` is the same for both. Copies derived
+    /// from a delta holding more than 11 kept months, e.g. through `bump_year`, `abs` or the scale methods, keep them
+    /// as well, while parsing always aggregates.
+    #[inline]
+    pub fn keep_months(self) -> Self {
+        Self {
+            keep_months: true,
+            ..self
+        }
+    }
+
+    // Error naming the first constant parameter outside its valid range, if any
+    fn out_of_range(ddt: &RelativeDelta) -> Option<BuildError> {
+        type Check = (Option<u32>, u32, u32, fn(u32) -> BuildError);
+        let absolutes: [Check; 7] = [
+            (ddt.month, 1, 12, BuildError::MonthOutOfRange),
+            (ddt.day, 1, 31, BuildError::DayOutOfRange),
+            (ddt.hour, 0, 23, BuildError::HourOutOfRange),
+            (ddt.minute, 0, 59, BuildError::MinuteOutOfRange),
+            (ddt.second, 0, 59, BuildError::SecondOutOfRange),
+            (
+                ddt.nanosecond,
+                0,
+                999_999_999,
+                BuildError::NanosecondOutOfRange,
+            ),
+            (
+                ddt.iso_week.map(|(_, week)| week as u32),
+                1,
+                53,
+                BuildError::IsoWeekOutOfRange,
+            ),
+        ];
+        absolutes
+            .iter()
+            .find_map(|&(value, min, max, err)| match value {
+                Some(v) if !(min..=max).contains(&v) => Some(err(v)),
+                _ => None,
+            })
+    }
+
+    // Carry relative parameters over into the next larger unit, so that e.g. hours are within -23..=23, and aggregate
+    // months into years unless `keep_months`. Constant parameters are not clamped, but panic when out of range, see
+    // `out_of_range`.
+    #[inline]
+    fn fix(ddt: &mut RelativeDelta, keep_months: bool) {
+        if let Some(err) = Self::out_of_range(ddt) {
+            panic!("{}", err);
+        }
 
         if ddt.nanoseconds.abs() > 999_999_999 {
             let s = ddt.nanoseconds.signum();
@@ -354,7 +834,11 @@ impl Builder {
             ddt.hours = rem * s;
             ddt.days += div * s;
         }
-        if ddt.months.abs() > 11 {
+        if !ddt.months_f.is_zero() && ddt.months_f.fract().is_zero() {
+            ddt.months += ddt.months_f as i64;
+            ddt.months_f = 0.0;
+        }
+        if !keep_months && ddt.months.abs() > 11 {
             let s = ddt.months.signum();
             let (div, rem) = (ddt.months * s).div_rem(&12);
             ddt.months = rem * s;
@@ -415,6 +899,14 @@ impl Builder {
             minutes: minutes as i64,
             seconds: seconds as i64,
             nanoseconds: nanosecs as i64,
+            set: SET_YEARS
+                | SET_MONTHS
+                | SET_MONTHS_F
+                | SET_DAYS
+                | SET_HOURS
+                | SET_MINUTES
+                | SET_SECONDS
+                | SET_NANOSECONDS,
             ..Self::default()
         }
     }
@@ -430,12 +922,465 @@ fn is_i64_zero(v: &i64) -> bool {
     *v == 0
 }
 
+#[cfg(feature = "serde")]
+fn is_false(v: &bool) -> bool {
+    !*v
+}
+
 #[cfg(feature = "serde")]
 fn is_f64_zero(v: &f64) -> bool {
     v.fract() == 0.0
 }
 
-/// RelativeDelta holding all data about the relative delta datetime.
+// RelativeDelta collecting any fields it does not know, for strict deserialization
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+struct StrictFields {
+    #[serde(flatten)]
+    delta: RelativeDelta,
+    #[serde(flatten)]
+    unknown: std::collections::BTreeMap<String, serde::de::IgnoredAny>,
+}
+
+// Every parameter of a RelativeDelta in order, serialized as a fixed sequence for formats which are not human readable
+#[cfg(feature = "serde")]
+#[derive(Serialize, Deserialize)]
+struct Compact(
+    i32,
+    i64,
+    f64,
+    i64,
+    i64,
+    i64,
+    i64,
+    i64,
+    Option<i32>,
+    Option<u32>,
+    Option<u32>,
+    Option<(chrono::Weekday, i64)>,
+    Option<u32>,
+    Option<u32>,
+    Option<u32>,
+    Option<u32>,
+    WeekStart,
+    Option<DayShift>,
+    bool,
+    Option<(chrono::Weekday, u8)>,
+    Anchor,
+    DayOverflow,
+);
+
+// Named fields for human readable formats like JSON, and the compact sequence for binary formats like bincode, as told
+// by `is_human_readable`. `RelativeDelta::serialize` and `RelativeDelta::deserialize` called directly always use the
+// named fields. Deserializing fails on constant parameters out of range, as `Builder::try_build` does, where the
+// inherent `RelativeDelta::deserialize` does not check them.
+#[cfg(feature = "serde")]
+impl Serialize for RelativeDelta {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            RelativeDelta::serialize(self, serializer)
+        } else {
+            Compact(
+                self.years,
+                self.months,
+                self.months_f,
+                self.days,
+                self.hours,
+                self.minutes,
+                self.seconds,
+                self.nanoseconds,
+                self.year,
+                self.month,
+                self.day,
+                self.weekday,
+                self.hour,
+                self.minute,
+                self.second,
+                self.nanosecond,
+                self.week_start,
+                self.day_shift,
+                self.pin_day,
+                self.iso_week,
+                self.weekday_anchor,
+                self.day_overflow,
+            )
+            .serialize(serializer)
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for RelativeDelta {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let delta = if deserializer.is_human_readable() {
+            RelativeDelta::deserialize(deserializer)?
+        } else {
+            Self::from_compact(Compact::deserialize(deserializer)?)
+        };
+        match Builder::out_of_range(&delta) {
+            Some(err) => Err(serde::de::Error::custom(err)),
+            None => Ok(delta),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl RelativeDelta {
+    fn from_compact(compact: Compact) -> RelativeDelta {
+        let Compact(
+            years,
+            months,
+            months_f,
+            days,
+            hours,
+            minutes,
+            seconds,
+            nanoseconds,
+            year,
+            month,
+            day,
+            weekday,
+            hour,
+            minute,
+            second,
+            nanosecond,
+            week_start,
+            day_shift,
+            pin_day,
+            iso_week,
+            weekday_anchor,
+            day_overflow,
+        ) = compact;
+        RelativeDelta {
+            years,
+            months,
+            months_f,
+            days,
+            hours,
+            minutes,
+            seconds,
+            nanoseconds,
+            year,
+            month,
+            day,
+            weekday,
+            hour,
+            minute,
+            second,
+            nanosecond,
+            week_start,
+            day_shift,
+            pin_day,
+            iso_week,
+            weekday_anchor,
+            day_overflow,
+        }
+    }
+}
+
+/// First day of the week used by week based calculations
+///
+/// Weekdays are otherwise handled through `num_days_from_monday`, and the week start simply offsets these, so for a
+/// week starting on sunday, sunday is day 0 and saturday day 6 of the week. Jumping to the nth weekday does not depend
+/// on the week start, as it only counts occurrences of the weekday itself.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct WeekStart(pub chrono::Weekday);
+
+impl WeekStart {
+    /// Days from the start of the week to the given weekday
+    #[inline]
+    pub fn days_into_week(&self, weekday: chrono::Weekday) -> u32 {
+        (7 + weekday.num_days_from_monday() - self.0.num_days_from_monday()) % 7
+    }
+
+    #[cfg(feature = "serde")]
+    fn is_default(&self) -> bool {
+        *self == WeekStart::default()
+    }
+}
+
+impl Default for WeekStart {
+    fn default() -> Self {
+        WeekStart(chrono::Weekday::Mon)
+    }
+}
+
+/// Shift to the nearest date within a set of weekdays, applied after all other parameters
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum DayShift {
+    /// Move ahead to the nearest monday to friday
+    NextWeekday,
+    /// Move back to the nearest monday to friday
+    PreviousWeekday,
+    /// Move ahead to the nearest saturday or sunday
+    NextWeekendDay,
+}
+
+impl DayShift {
+    // Days to jump from a date falling on `current`, zero if it already is within the set of weekdays
+    fn jump(&self, current: chrono::Weekday) -> i64 {
+        let weekend = matches!(current, chrono::Weekday::Sat | chrono::Weekday::Sun);
+        match self {
+            DayShift::NextWeekendDay if weekend => 0,
+            DayShift::NextWeekendDay => weekday_jump(current, chrono::Weekday::Sat, 1),
+            _ if !weekend => 0,
+            DayShift::NextWeekday => weekday_jump(current, chrono::Weekday::Mon, 1),
+            DayShift::PreviousWeekday => weekday_jump(current, chrono::Weekday::Fri, -1),
+        }
+    }
+}
+
+/// Date the weekday jump is computed from, relative to applying the relative days and time
+///
+/// With `AfterDays` the weekday of the date after adding days, hours etc. decides the jump, as in dateutil. With
+/// `BeforeDays` the jump is decided by the weekday of the date before adding them, and added on top of the days, e.g.
+/// 3 days and the next monday from a saturday then lands on thursday rather than the monday after.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Anchor {
+    BeforeDays,
+    #[default]
+    AfterDays,
+}
+
+impl Anchor {
+    #[cfg(feature = "serde")]
+    fn is_default(&self) -> bool {
+        *self == Anchor::default()
+    }
+}
+
+/// Handling of a day past the end of the month resolved when adding, e.g. a constant day 31 in february
+///
+/// Applies to the constant day as well as to the day kept from the date added to, e.g. a month from january 31st.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum DayOverflow {
+    /// Move back to the last day of the month, as in dateutil
+    #[default]
+    Clamp,
+    /// Carry the days past the end of the month over into the following month, e.g. february 31st is march 2nd or 3rd
+    Rollover,
+    /// Fail the addition, where the checked methods return None and the operators panic
+    Error,
+}
+
+impl DayOverflow {
+    #[cfg(feature = "serde")]
+    fn is_default(&self) -> bool {
+        *self == DayOverflow::default()
+    }
+}
+
+/// Named occurrence of a weekday, mapping to the nth used by `RelativeDelta::with_weekday`
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Ordinal {
+    First,
+    Second,
+    Third,
+    Fourth,
+    Fifth,
+    Last,
+    SecondToLast,
+}
+
+impl Ordinal {
+    /// The nth weekday, counting backwards when negative
+    pub fn nth(self) -> i64 {
+        match self {
+            Ordinal::First => 1,
+            Ordinal::Second => 2,
+            Ordinal::Third => 3,
+            Ordinal::Fourth => 4,
+            Ordinal::Fifth => 5,
+            Ordinal::Last => -1,
+            Ordinal::SecondToLast => -2,
+        }
+    }
+}
+
+/// Unit of a recurrence interval, see `RelativeDelta::every`
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Unit {
+    Year,
+    Month,
+    Week,
+    Day,
+    Hour,
+    Minute,
+    Second,
+}
+
+/// RelativeDelta ordered by total months only
+///
+/// RelativeDelta itself is only partially ordered, as e.g. a constant day lands before or after a relative one depending
+/// on the date it is added to. This wrapper compares purely by `total_months`, ignoring days, time and constant
+/// parameters, so deltas differing only in those compare equal. Useful as key in e.g. a BinaryHeap.
+#[derive(Copy, Clone, Debug)]
+pub struct ByTotalMonths(pub RelativeDelta);
+
+impl PartialEq for ByTotalMonths {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.total_months() == other.0.total_months()
+    }
+}
+
+impl Eq for ByTotalMonths {}
+
+impl PartialOrd for ByTotalMonths {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ByTotalMonths {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.0.total_months().cmp(&other.0.total_months())
+    }
+}
+
+/// Error returned when formatting a RelativeDelta with a format string fails
+#[derive(Clone, Debug, PartialEq)]
+pub enum FormatError {
+    /// The placeholder between the braces is not known
+    UnknownPlaceholder(String),
+    /// A brace was opened or closed without its counterpart
+    UnmatchedBrace,
+}
+
+impl std::fmt::Display for FormatError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FormatError::UnknownPlaceholder(name) => write!(f, "unknown placeholder {{{}}}", name),
+            FormatError::UnmatchedBrace => write!(f, "unmatched brace in format string"),
+        }
+    }
+}
+
+impl std::error::Error for FormatError {}
+
+/// Error returned when parsing a RelativeDelta from an ISO 8601 duration fails
+#[derive(Clone, Debug, PartialEq)]
+pub enum ParseError {
+    /// The duration does not start with `P`, optionally preceded by `-`
+    MissingPeriod,
+    /// The duration, or its time part after `T`, has no components
+    Empty,
+    /// A number which could not be parsed
+    InvalidNumber(String),
+    /// A number which is not followed by a designator
+    MissingDesignator(String),
+    /// A designator which is not known or out of order
+    UnexpectedDesignator(char),
+    /// A component does not fit within its parameter
+    Overflow,
+    /// A bracketed parameter which is not known or could not be parsed, described by the message
+    InvalidParameter(String),
+}
+
+impl std::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseError::MissingPeriod => write!(f, "duration must start with 'P'"),
+            ParseError::Empty => write!(f, "duration has no components"),
+            ParseError::InvalidNumber(num) => write!(f, "invalid number '{}'", num),
+            ParseError::MissingDesignator(num) => {
+                write!(f, "number '{}' is not followed by a designator", num)
+            }
+            ParseError::UnexpectedDesignator(c) => write!(f, "unexpected designator '{}'", c),
+            ParseError::Overflow => write!(f, "component out of range"),
+            ParseError::InvalidParameter(msg) => write!(f, "invalid parameter {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Error returned when building a RelativeDelta with a constant parameter outside its valid range
+#[derive(Clone, Debug, PartialEq)]
+pub enum BuildError {
+    /// The month is not within `1..=12`
+    MonthOutOfRange(u32),
+    /// The day is not within `1..=31`
+    DayOutOfRange(u32),
+    /// The hour is not within `0..=23`
+    HourOutOfRange(u32),
+    /// The minute is not within `0..=59`
+    MinuteOutOfRange(u32),
+    /// The second is not within `0..=59`
+    SecondOutOfRange(u32),
+    /// The nanosecond is not within `0..=999_999_999`
+    NanosecondOutOfRange(u32),
+    /// The week of the weekday in an ISO week is not within `1..=53`
+    IsoWeekOutOfRange(u32),
+}
+
+impl std::fmt::Display for BuildError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (name, value, max) = match *self {
+            BuildError::MonthOutOfRange(v) => ("month", v, 12),
+            BuildError::DayOutOfRange(v) => ("day", v, 31),
+            BuildError::HourOutOfRange(v) => ("hour", v, 23),
+            BuildError::MinuteOutOfRange(v) => ("minute", v, 59),
+            BuildError::SecondOutOfRange(v) => ("second", v, 59),
+            BuildError::NanosecondOutOfRange(v) => ("nanosecond", v, 999_999_999),
+            BuildError::IsoWeekOutOfRange(v) => ("ISO week", v, 53),
+        };
+        let min = match self {
+            BuildError::MonthOutOfRange(_)
+            | BuildError::DayOutOfRange(_)
+            | BuildError::IsoWeekOutOfRange(_) => 1,
+            _ => 0,
+        };
+        write!(f, "{} {} out of range {}..={}", name, value, min, max)
+    }
+}
+
+impl std::error::Error for BuildError {}
+
+/// Policy for resolving local times that are ambiguous or nonexistent in a named timezone
+#[cfg(feature = "chrono-tz")]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Disambiguation {
+    /// Pick the earliest instant matching the local time
+    Earliest,
+    /// Pick the latest instant matching the local time
+    Latest,
+    /// Give up on ambiguous or nonexistent local times
+    Reject,
+}
+
+/// Error returned when converting a RelativeDelta into a date or time fails
+#[derive(Clone, Debug, PartialEq)]
+pub enum FromError {
+    /// One or more of the absolute year, month and day are not set
+    MissingDateComponents,
+    /// The absolute components do not make up a valid date and time
+    InvalidDateComponents,
+    /// A specific absolute component is invalid, described by the message
+    InvalidComponent(String),
+    /// Parameters without a fixed length, such as months or a constant day, are set
+    NonExactDuration,
+}
+
+impl std::fmt::Display for FromError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FromError::MissingDateComponents => write!(f, "year, month and day must all be set"),
+            FromError::InvalidDateComponents => write!(f, "invalid date or time components"),
+            FromError::InvalidComponent(msg) => write!(f, "{}", msg),
+            FromError::NonExactDuration => write!(f, "only days and time have a fixed length"),
+        }
+    }
+}
+
+impl std::error::Error for FromError {}
+
+/// RelativeDelta holding all data about the relative delta datetime.
 ///
 /// If the relative delta date time is simple e.g. manipulating only a sigle time parameter, use one of the convenience
 /// methods to create a builder, and then call new to get the final RelativeDelta.
@@ -444,7 +1389,8 @@ fn is_f64_zero(v: &f64) -> bool {
 /// normalized and only calculated once.
 ///
 /// After creation the RelativeDelta can be added or substracted with itself or a chrono::DateTime object.
-/// Multiplication with f64 is possible as well. All operators are commutative.
+/// Multiplication with f64 or i64 is possible as well, scaling only the relative parameters while keeping the constant
+/// parameters and the weekday. All operators are commutative.
 ///
 /// # Examples
 ///
@@ -522,7 +1468,11 @@ fn is_f64_zero(v: &f64) -> bool {
 /// assert_eq!(d, Utc.ymd(2021, 1, 4).and_hms(0,0,0));
 /// ```
 #[derive(Copy, Clone, Debug, Default, PartialEq)]
-#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(remote = "Self")
+)]
 pub struct RelativeDelta {
     #[cfg_attr(
         feature = "serde",
@@ -621,6 +1571,78 @@ pub struct RelativeDelta {
         serde(default)
     )]
     weekday: Option<(chrono::Weekday, i64)>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "WeekStart::is_default"),
+        serde(default)
+    )]
+    week_start: WeekStart,
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "Option::is_none"),
+        serde(default)
+    )]
+    day_shift: Option<DayShift>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "is_false"),
+        serde(default)
+    )]
+    pin_day: bool,
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "Option::is_none"),
+        serde(default)
+    )]
+    iso_week: Option<(chrono::Weekday, u8)>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "Anchor::is_default"),
+        serde(default)
+    )]
+    weekday_anchor: Anchor,
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "DayOverflow::is_default"),
+        serde(default)
+    )]
+    day_overflow: DayOverflow,
+}
+
+/// Every parameter of a RelativeDelta as public fields, see `RelativeDelta::to_fields` and `RelativeDelta::from_parts`
+///
+/// Named after the getters of RelativeDelta, so e.g. `weekday_overrides_day` is true unless the day is pinned.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RelativeDeltaFields {
+    pub years: i32,
+    pub months: i64,
+    pub months_f: f64,
+    pub days: i64,
+    pub hours: i64,
+    pub minutes: i64,
+    pub seconds: i64,
+    pub nanoseconds: i64,
+    pub year: Option<i32>,
+    pub month: Option<u32>,
+    pub day: Option<u32>,
+    pub weekday: Option<(chrono::Weekday, i64)>,
+    pub hour: Option<u32>,
+    pub minute: Option<u32>,
+    pub second: Option<u32>,
+    pub nanosecond: Option<u32>,
+    pub week_start: WeekStart,
+    pub day_shift: Option<DayShift>,
+    pub weekday_overrides_day: bool,
+    pub iso_week: Option<(chrono::Weekday, u8)>,
+    pub weekday_relative_to: Anchor,
+    pub day_overflow: DayOverflow,
+}
+
+impl Default for RelativeDeltaFields {
+    fn default() -> Self {
+        RelativeDelta::default().to_fields()
+    }
 }
 
 impl RelativeDelta {
@@ -658,6 +1680,7 @@ impl RelativeDelta {
             months,
             day,
             days,
+            set: SET_YEAR | SET_YEARS | SET_MONTH | SET_MONTHS | SET_DAY | SET_DAYS,
             ..Default::default()
         }
     }
@@ -679,6 +1702,7 @@ impl RelativeDelta {
             minutes,
             second,
             seconds,
+            set: SET_HOUR | SET_HOURS | SET_MINUTE | SET_MINUTES | SET_SECOND | SET_SECONDS,
             ..Default::default()
         }
     }
@@ -689,6 +1713,7 @@ impl RelativeDelta {
     pub fn with_years(years: i32) -> Builder {
         Builder {
             years,
+            set: SET_YEARS,
             ..Default::default()
         }
     }
@@ -698,6 +1723,7 @@ impl RelativeDelta {
     pub fn with_months(months: i64) -> Builder {
         Builder {
             months,
+            set: SET_MONTHS,
             ..Default::default()
         }
     }
@@ -707,70 +1733,221 @@ impl RelativeDelta {
     pub fn with_days(days: i64) -> Builder {
         Builder {
             days,
+            set: SET_DAYS,
+            ..Default::default()
+        }
+    }
+
+    /// Convenience construction of a RelativeDelta (Builder) with only relative weeks parameter
+    ///
+    /// Weeks are held as 7 days each, like dateutil's `weeks`.
+    #[inline]
+    pub fn with_weeks(weeks: i64) -> Builder {
+        Builder {
+            weeks,
+            set: SET_WEEKS,
             ..Default::default()
         }
     }
 
+    /// Convenience construction of a RelativeDelta (Builder) stepping interval units, e.g. every 2 weeks
+    ///
+    /// Years are held as 12 months each, aggregated back into years on creation, and weeks as 7 days each.
+    #[inline]
+    pub fn every(interval: i64, unit: Unit) -> Builder {
+        match unit {
+            Unit::Year => Self::with_months(interval * 12),
+            Unit::Month => Self::with_months(interval),
+            Unit::Week => Self::with_weeks(interval),
+            Unit::Day => Self::with_days(interval),
+            Unit::Hour => Self::with_hours(interval),
+            Unit::Minute => Self::with_minutes(interval),
+            Unit::Second => Self::with_seconds(interval),
+        }
+    }
+
     /// Convenience construction of a RelativeDelta (Builder) with only relative hours parameter
     #[inline]
     pub fn with_hours(hours: i64) -> Builder {
         Builder {
             hours,
+            set: SET_HOURS,
+            ..Default::default()
+        }
+    }
+
+    /// Convenience construction of a RelativeDelta (Builder) from a total number of months
+    ///
+    /// With keep_as_months the total is kept in months as is, see `Builder::keep_months`, otherwise whole years are
+    /// rolled up into years as usual.
+    #[inline]
+    pub fn from_total_months(total: i64, keep_as_months: bool) -> Builder {
+        Builder {
+            months: total,
+            keep_months: keep_as_months,
+            set: SET_MONTHS,
             ..Default::default()
         }
     }
 
+    /// Convenience construction of a RelativeDelta (Builder) with relative weeks given as float
+    ///
+    /// Whole weeks are held as 7 days each, and the fractional week is distributed into days, hours, minutes, seconds
+    /// and nanoseconds, so e.g. 1.5 weeks is 10 days and 12 hours.
+    #[inline]
+    pub fn with_weeks_f(weeks: f64) -> Builder {
+        let mut builder = Builder::default();
+        builder.and_weeks_f(weeks);
+        builder
+    }
+
+    /// Convenience construction of a RelativeDelta (Builder) with relative days given as float
+    ///
+    /// Unlike fractional months, a fractional day is unambiguous as a day is taken as a fixed 24 hours. The fractional
+    /// part is distributed into hours, minutes, seconds and nanoseconds. Note that across a DST transition in a
+    /// chrono::DateTime with a local timezone, 1.5 days is therefore not the same as noon the next calendar day.
+    #[inline]
+    pub fn with_days_f(days: f64) -> Builder {
+        let mut builder = Builder::default();
+        builder.and_days_f(days);
+        builder
+    }
+
+    /// Convenience construction of a RelativeDelta (Builder) with relative hours given as float
+    ///
+    /// The fractional part is distributed into minutes, seconds and nanoseconds.
+    #[inline]
+    pub fn with_hours_f(hours: f64) -> Builder {
+        let mut builder = Builder::default();
+        builder.and_hours_f(hours);
+        builder
+    }
+
     /// Convenience construction of a RelativeDelta (Builder) with only relative minutes parameter
     #[inline]
     pub fn with_minutes(minutes: i64) -> Builder {
         Builder {
             minutes,
+            set: SET_MINUTES,
             ..Default::default()
         }
     }
 
+    /// Convenience construction of a RelativeDelta (Builder) with relative minutes given as float
+    ///
+    /// The fractional part is distributed into seconds and nanoseconds.
+    #[inline]
+    pub fn with_minutes_f(minutes: f64) -> Builder {
+        let mut builder = Builder::default();
+        builder.and_minutes_f(minutes);
+        builder
+    }
+
     /// Convenience construction of a RelativeDelta (Builder) with only relative seconds parameter
     #[inline]
     pub fn with_seconds(seconds: i64) -> Builder {
         Builder {
             seconds,
+            set: SET_SECONDS,
             ..Default::default()
         }
     }
 
+    /// Convenience construction of a RelativeDelta (Builder) with relative seconds given as float
+    ///
+    /// The fractional part is kept as nanoseconds, with the same sign as the seconds.
+    #[inline]
+    pub fn with_seconds_f(seconds: f64) -> Builder {
+        let mut builder = Builder::default();
+        builder.and_seconds_f(seconds);
+        builder
+    }
+
     /// Convenience construction of a RelativeDelta (Builder) with only relative nanoseconds parameter
     #[inline]
     pub fn with_nanoseconds(nanoseconds: i64) -> Builder {
         Builder {
             nanoseconds,
+            set: SET_NANOSECONDS,
             ..Default::default()
         }
     }
 
+    /// Convenience construction of a RelativeDelta (Builder) with only relative milliseconds parameter, see
+    /// `Builder::and_milliseconds`
+    #[inline]
+    pub fn with_milliseconds(milliseconds: i64) -> Builder {
+        let mut builder = Builder::default();
+        builder.and_milliseconds(milliseconds);
+        builder
+    }
+
+    /// Convenience construction of a RelativeDelta (Builder) with only relative microseconds parameter, see
+    /// `Builder::and_microseconds`
+    #[inline]
+    pub fn with_microseconds(microseconds: i64) -> Builder {
+        let mut builder = Builder::default();
+        builder.and_microseconds(microseconds);
+        builder
+    }
+
     // Constants
     /// Convenience construction of a RelativeDelta (Builder) with only constant year parameter
     #[inline]
     pub fn with_year(year: i32) -> Builder {
         Builder {
             year: Some(year),
+            set: SET_YEAR,
             ..Default::default()
         }
     }
 
+    /// Convenience construction of a RelativeDelta (Builder) with constant year expanded from a two digit year
+    ///
+    /// Years from the pivot and up are taken as 19yy, years below it as 20yy, so with pivot 70, 70 becomes 1970 and 20
+    /// becomes 2020.
+    #[inline]
+    pub fn with_two_digit_year(yy: u8, pivot: u8) -> Builder {
+        assert!(yy < 100, "two digit year {} out of range 0..=99", yy);
+        let century = if yy >= pivot { 1900 } else { 2000 };
+        Self::with_year(century + yy as i32)
+    }
+
     /// Convenience construction of a RelativeDelta (Builder) with only constant month parameter
     #[inline]
     pub fn with_month(month: u32) -> Builder {
         Builder {
             month: Some(month),
+            set: SET_MONTH,
+            ..Default::default()
+        }
+    }
+
+    /// Convenience construction of a RelativeDelta (Builder) with a constant month where 0 keeps the month as is
+    ///
+    /// Eases porting code using 0 for an unset month. Panics if the month is above 12.
+    #[inline]
+    pub fn with_month_or_keep(m: u8) -> Builder {
+        assert!(m <= 12, "month {} out of range 0..=12", m);
+        Builder {
+            month: if m == 0 { None } else { Some(m as u32) },
+            set: SET_MONTH,
             ..Default::default()
         }
     }
 
+    /// Convenience construction of a RelativeDelta (Builder) with only constant month parameter given as chrono::Month
+    #[inline]
+    pub fn with_month_enum(month: chrono::Month) -> Builder {
+        Self::with_month(month.number_from_month())
+    }
+
     /// Convenience construction of a RelativeDelta (Builder) with only constant day parameter
     #[inline]
     pub fn with_day(day: u32) -> Builder {
         Builder {
             day: Some(day),
+            set: SET_DAY,
             ..Default::default()
         }
     }
@@ -780,6 +1957,7 @@ impl RelativeDelta {
     pub fn with_hour(hour: u32) -> Builder {
         Builder {
             hour: Some(hour),
+            set: SET_HOUR,
             ..Default::default()
         }
     }
@@ -789,6 +1967,7 @@ impl RelativeDelta {
     pub fn with_minute(minute: u32) -> Builder {
         Builder {
             minute: Some(minute),
+            set: SET_MINUTE,
             ..Default::default()
         }
     }
@@ -798,6 +1977,7 @@ impl RelativeDelta {
     pub fn with_second(second: u32) -> Builder {
         Builder {
             second: Some(second),
+            set: SET_SECOND,
             ..Default::default()
         }
     }
@@ -807,14 +1987,165 @@ impl RelativeDelta {
     pub fn with_nanosecond(nanosecond: u32) -> Builder {
         Builder {
             nanosecond: Some(nanosecond),
+            set: SET_NANOSECOND,
+            ..Default::default()
+        }
+    }
+
+    /// Convenience construction of a RelativeDelta (Builder) with only constant second and nanosecond parameters
+    ///
+    /// The fractional second is split into whole seconds and nanoseconds, rounded to the nearest nanosecond.
+    #[inline]
+    pub fn with_fractional_second(secs: f64) -> Builder {
+        assert!(
+            (0.0..60.0).contains(&secs),
+            "fractional second {} out of range 0..60",
+            secs
+        );
+        let second = secs.trunc();
+        let nanosecond = ((secs - second) * 1_000_000_000_f64).round() as u32;
+        Builder {
+            second: Some(second as u32),
+            nanosecond: Some(nanosecond.min(999_999_999)),
+            set: SET_SECOND | SET_NANOSECOND,
             ..Default::default()
         }
     }
 
+    /// Convenience construction of a RelativeDelta (Builder) with only the weekday set
+    ///
+    /// The weekday is resolved after all other parameters, so when combined with a constant day the jump starts from
+    /// that day, and may move the date off it. Use `Builder::and_weekday_overrides_day(false)` to keep the constant day
+    /// instead.
+    ///
+    /// Parameters are applied in the order constant parameters, relative years and months, relative days and time,
+    /// ISO week, weekday and finally day shift. So by default the jump is computed from the date after the relative
+    /// days, e.g. 3 days and the next monday from a saturday is the monday after the tuesday reached by the days. Use
+    /// `Builder::and_weekday_relative_to(Anchor::BeforeDays)` to compute the jump from the date before adding days.
+    ///
+    /// Occurrences are counted from the date the delta is added to, inclusive, rather than from the start of its month
+    /// as in rrule. Combine with `and_day(Some(1))` to count from the start of the month instead.
+    #[doc(alias = "nth_weekday_from_anchor")]
     #[inline]
     pub fn with_weekday(weekday: chrono::Weekday, nth: i64) -> Builder {
         Builder {
             weekday: Some((weekday, nth)),
+            set: SET_WEEKDAY,
+            ..Default::default()
+        }
+    }
+
+    /// Convenience construction of a RelativeDelta (Builder) with the nth last weekday, like dateutil's `MO(-n)`
+    ///
+    /// n is 1 for the last occurrence, 2 for the second to last and so on, counting backwards from the resolved date
+    /// and including it. Combine with `and_day(Some(31))` for the nth last weekday of the month. Panics if n is 0.
+    #[inline]
+    pub fn last_nth_weekday(weekday: chrono::Weekday, n: u32) -> Builder {
+        assert!(n > 0, "nth last weekday {} out of range 1..", n);
+        Self::with_weekday(weekday, -(n as i64))
+    }
+
+    /// Convenience construction of a RelativeDelta (Builder) with only the weekday set, counted by name
+    ///
+    /// Same as `with_weekday` with the nth of the ordinal, e.g. `with_ordinal_weekday(Ordinal::Last, Weekday::Mon)`
+    /// combined with day 31 of may resolves to memorial day.
+    #[inline]
+    pub fn with_ordinal_weekday(ord: Ordinal, weekday: chrono::Weekday) -> Builder {
+        Self::with_weekday(weekday, ord.nth())
+    }
+
+    /// Convenience construction of a RelativeDelta (Builder) resolving to the nth weekday of an absolute month
+    ///
+    /// Sets the constant month, the constant day and the weekday in one go, so e.g. the 2nd tuesday of march is resolved
+    /// the same regardless of the date it is added to. A positive nth counts from the 1st of the month, a negative nth
+    /// from the 31st, clamped to the last day, so e.g. -1 is the last tuesday of the month.
+    #[inline]
+    pub fn on_weekday_of_month(weekday: chrono::Weekday, nth: i64, month: u32) -> Builder {
+        Builder {
+            month: Some(month),
+            day: Some(if nth < 0 { 31 } else { 1 }),
+            weekday: Some((weekday, nth)),
+            set: SET_MONTH | SET_DAY | SET_WEEKDAY,
+            ..Default::default()
+        }
+    }
+
+    /// Convenience construction of a RelativeDelta (Builder) resolving to n days before the last day of the month
+    ///
+    /// Moves to the 1st of the following month and steps back 1 + n days, so `days_before_month_end(0)` yields the last
+    /// day of the month the delta is added to, whatever its length.
+    #[inline]
+    pub fn days_before_month_end(n: i64) -> Builder {
+        Builder {
+            months: 1,
+            days: -(1 + n),
+            day: Some(1),
+            set: SET_MONTHS | SET_DAYS | SET_DAY,
+            ..Default::default()
+        }
+    }
+
+    /// Convenience construction of a RelativeDelta (Builder) resolving to a weekday in an ISO week of the resolved year
+    ///
+    /// The date is moved to the weekday of the given ISO week after all relative parameters are applied, so it may end
+    /// up in the previous or next calendar year, e.g. monday of week 1 of 2021 is 2021-01-04 while monday of week 1 of
+    /// 2020 is 2019-12-30. The week is clamped to the number of ISO weeks in the year, like days are to the month.
+    #[inline]
+    pub fn weekday_in_iso_week(weekday: chrono::Weekday, week: u8) -> Builder {
+        assert!(
+            (1..=53).contains(&week),
+            "ISO week {} out of range 1..=53",
+            week
+        );
+        Builder {
+            iso_week: Some((weekday, week)),
+            set: SET_ISO_WEEK,
+            ..Default::default()
+        }
+    }
+
+    /// Convenience construction of a RelativeDelta (Builder) with only the first day of the week set
+    #[inline]
+    pub fn with_week_start(week_start: WeekStart) -> Builder {
+        Builder {
+            week_start,
+            set: SET_WEEK_START,
+            ..Default::default()
+        }
+    }
+
+    /// Convenience construction of a RelativeDelta (Builder) moving to the nearest weekday (monday to friday) ahead
+    ///
+    /// The shift is applied after all other parameters, and leaves dates already on a weekday untouched.
+    #[inline]
+    pub fn next_weekday() -> Builder {
+        Builder {
+            day_shift: Some(DayShift::NextWeekday),
+            set: SET_DAY_SHIFT,
+            ..Default::default()
+        }
+    }
+
+    /// Convenience construction of a RelativeDelta (Builder) moving to the nearest weekday (monday to friday) behind
+    ///
+    /// The shift is applied after all other parameters, and leaves dates already on a weekday untouched.
+    #[inline]
+    pub fn previous_weekday() -> Builder {
+        Builder {
+            day_shift: Some(DayShift::PreviousWeekday),
+            set: SET_DAY_SHIFT,
+            ..Default::default()
+        }
+    }
+
+    /// Convenience construction of a RelativeDelta (Builder) moving to the nearest saturday or sunday ahead
+    ///
+    /// The shift is applied after all other parameters, and leaves dates already in a weekend untouched.
+    #[inline]
+    pub fn next_weekend_day() -> Builder {
+        Builder {
+            day_shift: Some(DayShift::NextWeekendDay),
+            set: SET_DAY_SHIFT,
             ..Default::default()
         }
     }
@@ -844,6 +2175,12 @@ impl RelativeDelta {
         self.days
     }
 
+    /// Whole weeks in the relative days, truncated towards zero
+    #[inline]
+    pub fn weeks(&self) -> i64 {
+        self.days / 7
+    }
+
     #[inline]
     pub fn day(&self) -> Option<u32> {
         self.day
@@ -894,60 +2231,1777 @@ impl RelativeDelta {
         self.weekday
     }
 
-    /// Calculate total months given the current months and years
     #[inline]
-    pub fn total_months(&self) -> i64 {
-        (self.years as i64) * 12 + self.months
+    pub fn week_start(&self) -> WeekStart {
+        self.week_start
     }
 
+    /// Whether the weekday may move the date off a set constant day, true unless pinned through the Builder
     #[inline]
-    pub fn is_empty(&self) -> bool {
-        self.year == None
-            && [
-                self.month,
-                self.day,
-                self.hour,
-                self.minute,
-                self.second,
-                self.nanosecond,
-            ]
-            .iter()
-            .all(|t| t == &None)
-            && self.years == 0
-            && [
-                self.months,
-                self.days,
-                self.hours,
-                self.minutes,
-                self.seconds,
-                self.nanoseconds,
-            ]
-            .iter()
-            .all(|t| t == &0)
-            && self.months_f.is_zero()
-            && self.weekday == None
+    pub fn weekday_overrides_day(&self) -> bool {
+        !self.pin_day
+    }
+
+    // Weekday to jump to on addition, None when a constant day is pinned
+    #[inline]
+    fn effective_weekday(&self) -> Option<(chrono::Weekday, i64)> {
+        if self.pin_day && self.day.is_some() {
+            None
+        } else {
+            self.weekday
+        }
+    }
+
+    #[inline]
+    pub fn day_shift(&self) -> Option<DayShift> {
+        self.day_shift
+    }
+
+    #[inline]
+    pub fn iso_week(&self) -> Option<(chrono::Weekday, u8)> {
+        self.iso_week
+    }
+
+    #[inline]
+    pub fn weekday_relative_to(&self) -> Anchor {
+        self.weekday_anchor
+    }
+
+    #[inline]
+    pub fn day_overflow(&self) -> DayOverflow {
+        self.day_overflow
+    }
+
+    /// Every parameter as public fields, e.g. for code generation or deserialization glue
+    pub fn to_fields(&self) -> RelativeDeltaFields {
+        RelativeDeltaFields {
+            years: self.years,
+            months: self.months,
+            months_f: self.months_f,
+            days: self.days,
+            hours: self.hours,
+            minutes: self.minutes,
+            seconds: self.seconds,
+            nanoseconds: self.nanoseconds,
+            year: self.year,
+            month: self.month,
+            day: self.day,
+            weekday: self.weekday,
+            hour: self.hour,
+            minute: self.minute,
+            second: self.second,
+            nanosecond: self.nanosecond,
+            week_start: self.week_start,
+            day_shift: self.day_shift,
+            weekday_overrides_day: !self.pin_day,
+            iso_week: self.iso_week,
+            weekday_relative_to: self.weekday_anchor,
+            day_overflow: self.day_overflow,
+        }
+    }
+
+    /// Construct from every parameter at once, normalized like `Builder::new`
+    ///
+    /// Round trips with `to_fields`, except for months kept through `Builder::keep_months`, which are aggregated into
+    /// years again. Panics if a constant parameter is out of range, like `Builder::new`.
+    pub fn from_parts(parts: RelativeDeltaFields) -> RelativeDelta {
+        Builder {
+            years: parts.years,
+            months: parts.months,
+            months_f: parts.months_f,
+            days: parts.days,
+            hours: parts.hours,
+            minutes: parts.minutes,
+            seconds: parts.seconds,
+            nanoseconds: parts.nanoseconds,
+            year: parts.year,
+            month: parts.month,
+            day: parts.day,
+            weekday: parts.weekday,
+            hour: parts.hour,
+            minute: parts.minute,
+            second: parts.second,
+            nanosecond: parts.nanosecond,
+            week_start: parts.week_start,
+            day_shift: parts.day_shift,
+            pin_day: !parts.weekday_overrides_day,
+            iso_week: parts.iso_week,
+            weekday_anchor: parts.weekday_relative_to,
+            day_overflow: parts.day_overflow,
+            ..Default::default()
+        }
+        .new()
+    }
+
+    // Re-fix a modified copy, bringing relative parameters back within their boundaries
+    fn refixed(mut self, keep_months: bool) -> Self {
+        Builder::fix(&mut self, keep_months);
+        self
+    }
+
+    // Whether months were kept through `Builder::keep_months`, as otherwise they never exceed 11
+    fn keeps_months(&self) -> bool {
+        self.months.abs() > 11
+    }
+
+    /// Copy with only the relative hours, minutes, seconds and nanoseconds multiplied by factor
+    ///
+    /// Relative years, months and days, constant parameters and the weekday are kept as is, except for whole days
+    /// carried over from the scaled time.
+    pub fn scale_time_only(&self, factor: f64) -> Self {
+        let t = Builder::normalize(
+            0.0,
+            0.0,
+            0.0,
+            self.hours as f64 * factor,
+            self.minutes as f64 * factor,
+            self.seconds as f64 * factor,
+            (self.nanoseconds as f64 * factor) as i64,
+        );
+        RelativeDelta {
+            days: self.days + t.days,
+            hours: t.hours,
+            minutes: t.minutes,
+            seconds: t.seconds,
+            nanoseconds: t.nanoseconds,
+            ..*self
+        }
+        .refixed(self.keeps_months())
+    }
+
+    /// Copy with only the relative years, months and days multiplied by factor
+    ///
+    /// Relative hours, minutes, seconds and nanoseconds, constant parameters and the weekday are kept as is, except for
+    /// time spilled over from fractional days.
+    pub fn scale_date_only(&self, factor: f64) -> Self {
+        let d = Builder::normalize(
+            self.years as f64 * factor,
+            (self.months as f64 + self.months_f) * factor,
+            self.days as f64 * factor,
+            0.0,
+            0.0,
+            0.0,
+            0,
+        );
+        RelativeDelta {
+            years: d.years,
+            months: d.months,
+            months_f: d.months_f,
+            days: d.days,
+            hours: self.hours + d.hours,
+            minutes: self.minutes + d.minutes,
+            seconds: self.seconds + d.seconds,
+            nanoseconds: self.nanoseconds + d.nanoseconds,
+            ..*self
+        }
+        .refixed(self.keeps_months())
+    }
+
+    /// Copy with relative years increased by n
+    ///
+    /// Panics if the relative years overflow i32.
+    #[inline]
+    pub fn bump_year(&self, n: i32) -> Self {
+        RelativeDelta {
+            years: self.years.checked_add(n).expect("years out of range"),
+            ..*self
+        }
+        .refixed(self.keeps_months())
+    }
+
+    /// Copy with relative months increased by n, carrying over into years
+    ///
+    /// Years and months are split again from the total months, so they always share the same sign. Panics if the
+    /// relative years overflow i32.
+    #[inline]
+    pub fn bump_month(&self, n: i64) -> Self {
+        let total = self
+            .total_months()
+            .checked_add(n)
+            .expect("months out of range");
+        RelativeDelta {
+            years: i32::try_from(total / 12).expect("years out of range"),
+            months: total % 12,
+            ..*self
+        }
+    }
+
+    /// Copy with relative days increased by n
+    #[inline]
+    pub fn bump_day(&self, n: i64) -> Self {
+        RelativeDelta {
+            days: self.days + n,
+            ..*self
+        }
+        .refixed(self.keeps_months())
+    }
+
+    /// Copy with relative hours increased by n, carrying over into days
+    #[inline]
+    pub fn bump_hour(&self, n: i64) -> Self {
+        RelativeDelta {
+            hours: self.hours + n,
+            ..*self
+        }
+        .refixed(self.keeps_months())
+    }
+
+    /// Copy with relative minutes increased by n, carrying over into hours
+    #[inline]
+    pub fn bump_minute(&self, n: i64) -> Self {
+        RelativeDelta {
+            minutes: self.minutes + n,
+            ..*self
+        }
+        .refixed(self.keeps_months())
+    }
+
+    /// Copy with relative seconds increased by n, carrying over into minutes
+    #[inline]
+    pub fn bump_second(&self, n: i64) -> Self {
+        RelativeDelta {
+            seconds: self.seconds + n,
+            ..*self
+        }
+        .refixed(self.keeps_months())
+    }
+
+    /// Calculate total months given the current months and years
+    #[inline]
+    pub fn total_months(&self) -> i64 {
+        (self.years as i64) * 12 + self.months
+    }
+
+    /// Relative months including those aggregated into years, e.g. -13 for `with_months(-13)`
+    ///
+    /// Months are aggregated into years on creation, so `months` only returns the remainder, -1 in the example, with
+    /// the -1 year read from `years`. This returns the total instead, and is the same as `total_months`.
+    #[inline]
+    pub fn signed_months(&self) -> i64 {
+        self.total_months()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.nonzero_component_count() == 0
+    }
+
+    /// Number of parameters set, counting non-zero relative parameters, constant parameters, the weekday, ISO week and
+    /// day shift
+    ///
+    /// Week start and the other modifiers of how the weekday is resolved are not counted. Handy for e.g. choosing
+    /// between a terse and a verbose format.
+    pub fn nonzero_component_count(&self) -> usize {
+        let constants = [
+            self.month,
+            self.day,
+            self.hour,
+            self.minute,
+            self.second,
+            self.nanosecond,
+        ];
+        let relatives = [
+            self.years as i64,
+            self.months,
+            self.days,
+            self.hours,
+            self.minutes,
+            self.seconds,
+            self.nanoseconds,
+        ];
+        self.year.is_some() as usize
+            + constants.iter().filter(|t| t.is_some()).count()
+            + relatives.iter().filter(|&&t| t != 0).count()
+            + !self.months_f.is_zero() as usize
+            + self.weekday.is_some() as usize
+            + self.day_shift.is_some() as usize
+            + self.iso_week.is_some() as usize
+    }
+
+    /// Count the occurrences of a weekday in the month resolved by adding self to a chrono::DateTime
+    pub fn weekday_count_in_month<Tz: chrono::TimeZone>(
+        &self,
+        dt: &chrono::DateTime<Tz>,
+        weekday: chrono::Weekday,
+    ) -> u8 {
+        let resolved = dt + self;
+        let days = num_days_in_month(resolved.year(), resolved.month());
+        let first = chrono::NaiveDate::from_ymd_opt(resolved.year(), resolved.month(), 1)
+            .unwrap()
+            .weekday();
+        let offset = (7 + weekday.num_days_from_monday() - first.num_days_from_monday()) % 7;
+        (days - offset).div_ceil(7) as u8
+    }
+
+    /// Add to a chrono::DateTime, returning None instead of panicking
+    ///
+    /// None is returned where the `+` operator would panic, i.e. when the resolved date is out of range for chrono,
+    /// does not exist in the timezone of `dt`, or adding the relative days and time overflows.
+    pub fn checked_add_to<Tz: chrono::TimeZone>(
+        &self,
+        dt: &chrono::DateTime<Tz>,
+    ) -> Option<chrono::DateTime<Tz>> {
+        checked_add(self, dt)
+    }
+
+    /// Add to a time::PrimitiveDateTime, returning None instead of panicking
+    ///
+    /// Same as `checked_add_to`, with the date and time taken as UTC.
+    #[cfg(feature = "time")]
+    pub fn checked_add_to_primitive(
+        &self,
+        dt: &time::PrimitiveDateTime,
+    ) -> Option<time::PrimitiveDateTime> {
+        chrono_to_primitive(&self.checked_add_to(&primitive_to_chrono(dt)?)?)
+    }
+
+    /// Add to a chrono::DateTime, saturating at the bounds of chrono instead of panicking
+    ///
+    /// If the result would fall after `DateTime::<Utc>::MAX_UTC` or before `DateTime::<Utc>::MIN_UTC` (around year
+    /// +-262143), the respective bound is returned in the timezone of `dt`. Only results out of range saturate, so
+    /// like the `+` operator this panics when the resolved local time does not exist, e.g. in a DST gap, or on a day
+    /// past the end of the month with `DayOverflow::Error`.
+    pub fn saturating_add_to<Tz: chrono::TimeZone>(
+        &self,
+        dt: &chrono::DateTime<Tz>,
+    ) -> chrono::DateTime<Tz> {
+        match try_add(self, dt) {
+            Ok(ret) => ret,
+            Err(AddError::OutOfRange(forward)) => {
+                let bound = if forward {
+                    chrono::DateTime::<chrono::Utc>::MAX_UTC
+                } else {
+                    chrono::DateTime::<chrono::Utc>::MIN_UTC
+                };
+                bound.with_timezone(&dt.timezone())
+            }
+            Err(AddError::Invalid) => panic!("Could not add {:?} to {:?}", self, dt),
+        }
+    }
+
+    /// Subtract from a chrono::DateTime, saturating at the bounds of chrono instead of panicking
+    ///
+    /// See `saturating_add_to` for the clamp points.
+    pub fn saturating_sub_from<Tz: chrono::TimeZone>(
+        &self,
+        dt: &chrono::DateTime<Tz>,
+    ) -> chrono::DateTime<Tz> {
+        (-self).saturating_add_to(dt)
+    }
+
+    /// Format the relative parameters with a format string
+    ///
+    /// Supported placeholders are `{years}`, `{months}`, `{weeks}`, `{days}`, `{hours}`, `{minutes}`, `{seconds}` and
+    /// `{nanoseconds}`, where `{weeks}` is the whole number of weeks in days. Literal braces are written as `{{` and
+    /// `}}`.
+    ///
+    /// ```edition2018
+    /// # use relativedelta::RelativeDelta;
+    /// let rddt = RelativeDelta::with_years(1).and_months(2).and_days(3).new();
+    /// assert_eq!(rddt.format("{years}y {months}m {days}d").unwrap(), "1y 2m 3d");
+    /// ```
+    pub fn format(&self, fmt: &str) -> Result<String, FormatError> {
+        let mut out = String::with_capacity(fmt.len());
+        let mut chars = fmt.chars();
+        while let Some(c) = chars.next() {
+            match c {
+                '{' if chars.as_str().starts_with('{') => {
+                    chars.next();
+                    out.push('{');
+                }
+                '{' => {
+                    let rest = chars.as_str();
+                    let end = rest.find('}').ok_or(FormatError::UnmatchedBrace)?;
+                    let value = match &rest[..end] {
+                        "years" => self.years as i64,
+                        "months" => self.months,
+                        "weeks" => self.days / 7,
+                        "days" => self.days,
+                        "hours" => self.hours,
+                        "minutes" => self.minutes,
+                        "seconds" => self.seconds,
+                        "nanoseconds" => self.nanoseconds,
+                        name => return Err(FormatError::UnknownPlaceholder(name.to_string())),
+                    };
+                    out.push_str(&value.to_string());
+                    chars = rest[end + 1..].chars();
+                }
+                '}' if chars.as_str().starts_with('}') => {
+                    chars.next();
+                    out.push('}');
+                }
+                '}' => return Err(FormatError::UnmatchedBrace),
+                c => out.push(c),
+            }
+        }
+        Ok(out)
+    }
+
+    /// Add to a chrono::DateTime in a named timezone, computing on the wall clock
+    ///
+    /// All parameters are applied to the local date and time, after which the resulting local time is mapped back into
+    /// the timezone. Local times repeated by a DST transition are resolved per `disambiguate`, as are local times
+    /// skipped by one, where Earliest and Latest pick the instant given by the offset after and before the transition
+    /// respectively. Returns None if the local time is rejected.
+    ///
+    /// Relative days and the weekday jump thus move by calendar days keeping the local time of day, where adding with
+    /// `+` moves by exact 24 hour durations, e.g. shifting the wall clock an hour when jumping across spring forward.
+    #[cfg(feature = "chrono-tz")]
+    pub fn add_in_zone(
+        &self,
+        dt: chrono::DateTime<chrono_tz::Tz>,
+        disambiguate: Disambiguation,
+    ) -> Option<chrono::DateTime<chrono_tz::Tz>> {
+        use chrono::{LocalResult, Offset, TimeZone};
+
+        let tz = dt.timezone();
+        // Utc has no transitions, so arithmetic on it is pure wall clock arithmetic
+        let local = (self + dt.naive_local().and_utc()).naive_utc();
+        match (tz.from_local_datetime(&local), disambiguate) {
+            (LocalResult::Single(d), _) => Some(d),
+            (_, Disambiguation::Reject) => None,
+            (LocalResult::Ambiguous(earliest, _), Disambiguation::Earliest) => Some(earliest),
+            (LocalResult::Ambiguous(_, latest), Disambiguation::Latest) => Some(latest),
+            (LocalResult::None, _) => {
+                let day = chrono::Duration::days(1);
+                let before = tz.offset_from_utc_datetime(&(local - day)).fix();
+                let after = tz.offset_from_utc_datetime(&(local + day)).fix();
+                let (a, b) = (local - before, local - after);
+                let utc = match disambiguate {
+                    Disambiguation::Earliest => a.min(b),
+                    _ => a.max(b),
+                };
+                Some(tz.from_utc_datetime(&utc))
+            }
+        }
+    }
+
+    /// Convert the absolute parameters into a chrono::NaiveDateTime
+    ///
+    /// Year, month and day must be set, while hour, minute, second and nanosecond fall back to the given
+    /// `(hour, minute, second, nanosecond)` default when not set. `TryFrom` does the same with a default of midnight.
+    pub fn try_into_datetime_with_default_time(
+        &self,
+        default: (u8, u8, u8, u32),
+    ) -> Result<chrono::NaiveDateTime, FromError> {
+        let (hour, minute, second, nanosecond) = default;
+        match (self.year, self.month, self.day) {
+            (Some(year), Some(month), Some(day)) => {
+                chrono::NaiveDate::from_ymd_opt(year, month, day)
+                    .and_then(|d| {
+                        d.and_hms_nano_opt(
+                            self.hour.unwrap_or(hour as u32),
+                            self.minute.unwrap_or(minute as u32),
+                            self.second.unwrap_or(second as u32),
+                            self.nanosecond.unwrap_or(nanosecond),
+                        )
+                    })
+                    .ok_or(FromError::InvalidDateComponents)
+            }
+            _ => Err(FromError::MissingDateComponents),
+        }
+    }
+
+    /// Week of the month, starting from 1, that the date resolved by adding self to a chrono::DateTime falls in
+    ///
+    /// Weeks begin on the week start of self, with the days of the month before the first week start making up week 1.
+    pub fn week_of_month<Tz: chrono::TimeZone>(&self, dt: &chrono::DateTime<Tz>) -> u32 {
+        let resolved = dt + self;
+        let first = chrono::NaiveDate::from_ymd_opt(resolved.year(), resolved.month(), 1)
+            .unwrap()
+            .weekday();
+        (resolved.day0() + self.week_start.days_into_week(first)) / 7 + 1
+    }
+
+    /// String representation matching `str()` of a relativedelta in Python's dateutil
+    ///
+    /// E.g. `relativedelta(years=+1, months=+2, day=31, weekday=FR(-1))`. Only non-zero relative parameters and set
+    /// absolute parameters are included, and nanoseconds take the place of dateutil's microseconds.
+    pub fn to_dateutil_str(&self) -> String {
+        let mut parts = Vec::new();
+        let months = if self.months_f.is_zero() {
+            format!("{:+}", self.months)
+        } else {
+            format!("{:+}", self.months as f64 + self.months_f)
+        };
+        let relatives = [
+            ("years", format!("{:+}", self.years), self.years != 0),
+            (
+                "months",
+                months,
+                self.months != 0 || !self.months_f.is_zero(),
+            ),
+            ("days", format!("{:+}", self.days), self.days != 0),
+            ("hours", format!("{:+}", self.hours), self.hours != 0),
+            ("minutes", format!("{:+}", self.minutes), self.minutes != 0),
+            ("seconds", format!("{:+}", self.seconds), self.seconds != 0),
+            (
+                "nanoseconds",
+                format!("{:+}", self.nanoseconds),
+                self.nanoseconds != 0,
+            ),
+        ];
+        for (name, value, _) in relatives.iter().filter(|(_, _, set)| *set) {
+            parts.push(format!("{}={}", name, value));
+        }
+
+        let weekday = self.weekday.map(|(weekday, nth)| {
+            let name = match weekday {
+                chrono::Weekday::Mon => "MO",
+                chrono::Weekday::Tue => "TU",
+                chrono::Weekday::Wed => "WE",
+                chrono::Weekday::Thu => "TH",
+                chrono::Weekday::Fri => "FR",
+                chrono::Weekday::Sat => "SA",
+                chrono::Weekday::Sun => "SU",
+            };
+            format!("{}({:+})", name, nth)
+        });
+        let absolutes = [
+            ("year", self.year.map(|v| v.to_string())),
+            ("month", self.month.map(|v| v.to_string())),
+            ("day", self.day.map(|v| v.to_string())),
+            ("weekday", weekday),
+            ("hour", self.hour.map(|v| v.to_string())),
+            ("minute", self.minute.map(|v| v.to_string())),
+            ("second", self.second.map(|v| v.to_string())),
+            ("nanosecond", self.nanosecond.map(|v| v.to_string())),
+        ];
+        for (name, value) in absolutes.iter() {
+            if let Some(value) = value {
+                parts.push(format!("{}={}", name, value));
+            }
+        }
+        format!("relativedelta({})", parts.join(", "))
+    }
+
+    /// Convert the absolute parameters into a chrono::NaiveDateTime, reporting which component is invalid
+    ///
+    /// Like `TryFrom`, but every component is validated on its own, so the error names the failing component, e.g.
+    /// "day 30 invalid for month 2 of 2021". Unset hour, minute, second and nanosecond default to 0.
+    pub fn checked_into_naive_datetime(&self) -> Result<chrono::NaiveDateTime, FromError> {
+        let (year, month, day) = match (self.year, self.month, self.day) {
+            (Some(year), Some(month), Some(day)) => (year, month, day),
+            _ => return Err(FromError::MissingDateComponents),
+        };
+        let invalid = |msg: String| Err(FromError::InvalidComponent(msg));
+        let years = chrono::NaiveDate::MIN.year()..=chrono::NaiveDate::MAX.year();
+        if !years.contains(&year) {
+            return invalid(format!(
+                "year {} out of range {}..={}",
+                year,
+                years.start(),
+                years.end()
+            ));
+        }
+        if !(1..=12).contains(&month) {
+            return invalid(format!("month {} out of range 1..=12", month));
+        }
+        if day == 0 || day > num_days_in_month(year, month) {
+            return invalid(format!(
+                "day {} invalid for month {} of {}",
+                day, month, year
+            ));
+        }
+        let time = [
+            ("hour", self.hour.unwrap_or(0), 23),
+            ("minute", self.minute.unwrap_or(0), 59),
+            ("second", self.second.unwrap_or(0), 59),
+            ("nanosecond", self.nanosecond.unwrap_or(0), 999_999_999),
+        ];
+        for (name, value, max) in time.iter() {
+            if value > max {
+                return invalid(format!("{} {} out of range 0..={}", name, value, max));
+            }
+        }
+        chrono::NaiveDate::from_ymd_opt(year, month, day)
+            .and_then(|d| d.and_hms_nano_opt(time[0].1, time[1].1, time[2].1, time[3].1))
+            .ok_or(FromError::InvalidDateComponents)
+    }
+
+    /// ISO-8601 week duration representation, e.g. `P2W`
+    ///
+    /// Only deltas holding nothing but a whole number of weeks in days can be expressed in the week form, all other
+    /// deltas return None.
+    pub fn to_iso8601_weeks(&self) -> Option<String> {
+        let weeks_only = RelativeDelta { days: 0, ..*self };
+        if self.days == 0 || self.days % 7 != 0 || !weeks_only.is_empty() {
+            return None;
+        }
+        let sign = if self.days < 0 { "-" } else { "" };
+        Some(format!("{}P{}W", sign, (self.days / 7).abs()))
+    }
+
+    /// ISO-8601 duration representation with the sign placed in front, e.g. `-P1Y2DT3H`
+    ///
+    /// ISO-8601 only allows a single sign for the whole duration, so deltas mixing positive and negative relative
+    /// parameters, such as `with_days(2).and_hours(-1)`, cannot be represented and return None. The same goes for
+    /// deltas with constant parameters, a weekday or any of the other adjustments. Fractional months are written as a
+    /// decimal month, and nanoseconds as decimal seconds.
+    pub fn to_iso8601_canonical(&self) -> Option<String> {
+        let signs = self.relative_signs();
+        if (signs.contains(&1) && signs.contains(&-1)) || self.relative_only() != *self {
+            return None;
+        }
+        Some(self.to_string())
+    }
+
+    // Copy holding only the relative parameters
+    fn relative_only(&self) -> RelativeDelta {
+        RelativeDelta {
+            years: self.years,
+            months: self.months,
+            months_f: self.months_f,
+            days: self.days,
+            hours: self.hours,
+            minutes: self.minutes,
+            seconds: self.seconds,
+            nanoseconds: self.nanoseconds,
+            ..Default::default()
+        }
+    }
+
+    // Signs of the relative years, months, days, hours, minutes and seconds, with nanoseconds counted into the seconds
+    fn relative_signs(&self) -> [i8; 6] {
+        let months = self.months as f64 + self.months_f;
+        let nanos = self.seconds as i128 * 1_000_000_000 + self.nanoseconds as i128;
+        [
+            self.years.signum() as i8,
+            (months > 0.0) as i8 - (months < 0.0) as i8,
+            self.days.signum() as i8,
+            self.hours.signum() as i8,
+            self.minutes.signum() as i8,
+            nanos.signum() as i8,
+        ]
+    }
+
+    /// Total length in nanoseconds of a fixed length delta
+    ///
+    /// Only deltas holding nothing but days, hours, minutes, seconds and nanoseconds have a fixed length, all other
+    /// deltas return None.
+    pub fn total_nanoseconds(&self) -> Option<i128> {
+        let variable_only = RelativeDelta {
+            days: 0,
+            hours: 0,
+            minutes: 0,
+            seconds: 0,
+            nanoseconds: 0,
+            ..*self
+        };
+        if !variable_only.is_empty() {
+            return None;
+        }
+        let seconds = ((self.days as i128 * 24 + self.hours as i128) * 60 + self.minutes as i128)
+            * 60
+            + self.seconds as i128;
+        Some(seconds * 1_000_000_000 + self.nanoseconds as i128)
+    }
+
+    /// Checked construction of a RelativeDelta from the difference between two chrono::DateTime
+    ///
+    /// Returns a RelativeDelta holding only relative parameters, such that `earlier + delta == later`.
+    /// As in dateutil, whole months are counted first (clamping the day to the end of month where needed) and the
+    /// remainder is expressed in days, hours, minutes, seconds and nanoseconds.
+    /// Returns None instead of overflowing if the month arithmetic or any intermediate date is out of range.
+    pub fn checked_from_chrono_diff<Tz: chrono::TimeZone>(
+        later: &chrono::DateTime<Tz>,
+        earlier: &chrono::DateTime<Tz>,
+    ) -> Option<RelativeDelta> {
+        let mut months = checked_months_diff(
+            later.year() as i64,
+            later.month() as i64,
+            earlier.year() as i64,
+            earlier.month() as i64,
+        )?;
+        // Step back one month at a time if clamping or the time of day made us overshoot
+        let increment = if later < earlier { 1 } else { -1 };
+        let mut shifted = checked_add_months(earlier, months)?;
+        while (increment < 0 && later < &shifted) || (increment > 0 && later > &shifted) {
+            months = months.checked_add(increment)?;
+            shifted = checked_add_months(earlier, months)?;
+        }
+
+        let remainder = later.clone().signed_duration_since(shifted);
+        let seconds = remainder.num_seconds();
+        let nanoseconds = (remainder - chrono::Duration::seconds(seconds)).num_nanoseconds()?;
+        let (years, months) = (months / 12, months % 12);
+        Some(
+            RelativeDelta::with_years(i32::try_from(years).ok()?)
+                .and_months(months)
+                .and_seconds(seconds)
+                .and_nanoseconds(nanoseconds)
+                .new(),
+        )
+    }
+
+    /// Whole calendar months between two time::PrimitiveDateTime
+    ///
+    /// Counted the same way as `checked_from_chrono_diff`, clamping the day to the end of month where needed, so e.g.
+    /// from january 31st to february 28th is one month. Negative if later is before earlier.
+    #[cfg(feature = "time")]
+    pub fn time_months_between(
+        later: &time::PrimitiveDateTime,
+        earlier: &time::PrimitiveDateTime,
+    ) -> i64 {
+        let to_chrono =
+            |dt| primitive_to_chrono(dt).expect("time::PrimitiveDateTime out of range for chrono");
+        RelativeDelta::checked_from_chrono_diff(&to_chrono(later), &to_chrono(earlier))
+            .expect("months between out of range")
+            .total_months()
+    }
+
+    /// Construction of a RelativeDelta from the difference between two chrono::DateTime, like dateutil's
+    /// `relativedelta(dt1, dt2)`
+    ///
+    /// Panics if the difference does not fit, see `checked_from_chrono_diff` for details and a non panicking version.
+    pub fn between<Tz: chrono::TimeZone>(
+        later: &chrono::DateTime<Tz>,
+        earlier: &chrono::DateTime<Tz>,
+    ) -> RelativeDelta {
+        Self::checked_from_chrono_diff(later, earlier).expect("difference out of range")
+    }
+
+    /// Collect every occurrence of a weekday in the half open interval from start up to end
+    ///
+    /// Occurrences keep the time of day of start, so an occurrence on the same day as end but at a later time is still
+    /// excluded.
+    pub fn weekday_occurrences_between<Tz: chrono::TimeZone>(
+        start: &chrono::DateTime<Tz>,
+        end: &chrono::DateTime<Tz>,
+        weekday: chrono::Weekday,
+    ) -> Vec<chrono::DateTime<Tz>> {
+        let week = RelativeDelta::with_days(7).new();
+        let mut occurrences = Vec::new();
+        let mut current = start + RelativeDelta::with_weekday(weekday, 1).new();
+        while current < *end {
+            let next = &current + week;
+            occurrences.push(current);
+            current = next;
+        }
+        occurrences
+    }
+
+    /// Deserialize a RelativeDelta, failing on unknown fields instead of ignoring them
+    ///
+    /// The derived Deserialize skips unknown fields, so a typo like `"yaers": 1` silently deserializes into an empty
+    /// delta. Use this directly or through `#[serde(deserialize_with = "RelativeDelta::deserialize_strict")]` to catch
+    /// these. Only the named fields of human readable formats are accepted, not the compact sequence.
+    #[cfg(feature = "serde")]
+    pub fn deserialize_strict<'de, D>(deserializer: D) -> Result<RelativeDelta, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let fields = StrictFields::deserialize(deserializer)?;
+        match fields.unknown.keys().next() {
+            Some(key) => Err(serde::de::Error::custom(format!("unknown field `{}`", key))),
+            None => Ok(fields.delta),
+        }
+    }
+
+    /// Checked negation of the relative parameters, returning None if any of them overflows
+    ///
+    /// The `-` operator wraps instead, e.g. leaving `i32::MIN` years as is.
+    pub fn checked_neg(&self) -> Option<RelativeDelta> {
+        Some(RelativeDelta {
+            years: self.years.checked_neg()?,
+            months: self.months.checked_neg()?,
+            months_f: -self.months_f,
+            days: self.days.checked_neg()?,
+            hours: self.hours.checked_neg()?,
+            minutes: self.minutes.checked_neg()?,
+            seconds: self.seconds.checked_neg()?,
+            nanoseconds: self.nanoseconds.checked_neg()?,
+            ..*self
+        })
+    }
+
+    /// Copy with all relative parameters made positive, keeping constant parameters and the weekday
+    ///
+    /// Each parameter is made positive on its own, so for mixed signs this is not the magnitude of the delta, e.g. 1
+    /// day and -1 hour becomes 1 day and 1 hour. Wraps on overflow like the `-` operator.
+    pub fn abs(&self) -> RelativeDelta {
+        RelativeDelta {
+            years: self.years.wrapping_abs(),
+            months: self.months.wrapping_abs(),
+            months_f: self.months_f.abs(),
+            days: self.days.wrapping_abs(),
+            hours: self.hours.wrapping_abs(),
+            minutes: self.minutes.wrapping_abs(),
+            seconds: self.seconds.wrapping_abs(),
+            nanoseconds: self.nanoseconds.wrapping_abs(),
+            ..*self
+        }
+        .refixed(self.keeps_months())
+    }
+
+    /// Checked multiplication of the relative parameters, returning None if the years or days overflow
+    ///
+    /// Multiplying with `*` panics on overflow, in release builds as well. Pick `checked_mul`, `saturating_mul` or
+    /// `wrapping_mul` to make the overflow behaviour explicit. Time parameters carry over into days and months into
+    /// years before checking, so only the years and days can overflow.
+    pub fn checked_mul(&self, factor: i64) -> Option<RelativeDelta> {
+        let (years, months_f, rest) = self.mul_exact(factor);
+        Some(RelativeDelta {
+            years: i32::try_from(years).ok()?,
+            months: rest[0] as i64,
+            months_f,
+            days: i64::try_from(rest[1]).ok()?,
+            hours: rest[2] as i64,
+            minutes: rest[3] as i64,
+            seconds: rest[4] as i64,
+            nanoseconds: rest[5] as i64,
+            ..*self
+        })
+    }
+
+    /// Saturating multiplication of the relative parameters, clamping the years and days to their bounds on overflow
+    ///
+    /// See `checked_mul` for the overflow behaviour of the alternatives.
+    pub fn saturating_mul(&self, factor: i64) -> RelativeDelta {
+        let (years, months_f, rest) = self.mul_exact(factor);
+        RelativeDelta {
+            years: years.clamp(i32::MIN as i128, i32::MAX as i128) as i32,
+            months: rest[0] as i64,
+            months_f,
+            days: rest[1].clamp(i64::MIN as i128, i64::MAX as i128) as i64,
+            hours: rest[2] as i64,
+            minutes: rest[3] as i64,
+            seconds: rest[4] as i64,
+            nanoseconds: rest[5] as i64,
+            ..*self
+        }
+    }
+
+    /// Wrapping multiplication of the relative parameters, wrapping the years and days around their bounds on overflow
+    ///
+    /// See `checked_mul` for the overflow behaviour of the alternatives.
+    pub fn wrapping_mul(&self, factor: i64) -> RelativeDelta {
+        let (years, months_f, rest) = self.mul_exact(factor);
+        RelativeDelta {
+            years: years as i32,
+            months: rest[0] as i64,
+            months_f,
+            days: rest[1] as i64,
+            hours: rest[2] as i64,
+            minutes: rest[3] as i64,
+            seconds: rest[4] as i64,
+            nanoseconds: rest[5] as i64,
+            ..*self
+        }
+    }
+
+    // Relative parameters multiplied by factor without overflow, carried over like `Builder::fix` does. Returns the
+    // years, fractional months and the months, days, hours, minutes, seconds and nanoseconds, all but years and days
+    // within the bounds of their parameter.
+    fn mul_exact(&self, factor: i64) -> (i128, f64, [i128; 6]) {
+        let factor = factor as i128;
+        let mut months_f = self.months_f * factor as f64;
+        let mut years = self.years as i128 * factor;
+        let mut rest = [
+            self.months as i128 * factor,
+            self.days as i128 * factor,
+            self.hours as i128 * factor,
+            self.minutes as i128 * factor,
+            self.seconds as i128 * factor,
+            self.nanoseconds as i128 * factor,
+        ];
+        if !months_f.is_zero() && months_f.fract().is_zero() {
+            rest[0] += months_f as i128;
+            months_f = 0.0;
+        }
+        // Nanoseconds into seconds, seconds into minutes, minutes into hours and hours into days
+        for (i, limit) in [(5, 1_000_000_000), (4, 60), (3, 60), (2, 24)] {
+            rest[i - 1] += rest[i] / limit;
+            rest[i] %= limit;
+        }
+        years += rest[0] / 12;
+        rest[0] %= 12;
+        (years, months_f, rest)
+    }
+
+    /// Approximate length in seconds of the relative parameters, given a number of days per month and per year
+    ///
+    /// This is lossy, as months and years vary in length, and the constant parameters and weekday are ignored. Meant
+    /// for coarse comparisons, e.g. sorting deltas of mixed units.
+    pub fn approx_total_seconds(&self, days_per_month: f64, days_per_year: f64) -> f64 {
+        let days = self.years as f64 * days_per_year
+            + (self.months as f64 + self.months_f) * days_per_month
+            + self.days as f64;
+        ((days * 24_f64 + self.hours as f64) * 60_f64 + self.minutes as f64) * 60_f64
+            + self.seconds as f64
+            + self.nanoseconds as f64 / 1_000_000_000_f64
+    }
+
+    /// Approximate length in seconds of the relative parameters, taking a month as the average Gregorian month
+    ///
+    /// **Years and months are approximate**: their actual length depends on the date the delta is added to, while
+    /// here a month is always 30.436875 days and a year 12 of those. Days and time are exact. Constant parameters and
+    /// the weekday are ignored, so only use this for sorting deltas without them. See `approx_total_seconds` for other
+    /// month lengths.
+    pub fn total_seconds(&self) -> f64 {
+        self.approx_total_seconds(30.436875, 365.2425)
+    }
+
+    /// Whether the relative parameters span backwards in time, see `signum`
+    #[inline]
+    pub fn is_negative(&self) -> bool {
+        self.signum() < 0
+    }
+
+    /// Direction of the relative parameters, as the sign of `total_seconds`
+    ///
+    /// For mixed signs the years and months are weighed with the average Gregorian month, so e.g. 1 year and -400 days
+    /// is negative, while 1 year and -365 days is positive. The sign is computed in whole nanoseconds rather than from
+    /// the float, so it is exact. Returns 0 for deltas without any relative parameters, or whose parameters cancel out.
+    pub fn signum(&self) -> i8 {
+        let fixed = ((self.days as i128 * 24 + self.hours as i128) * 60 + self.minutes as i128)
+            * 60
+            + self.seconds as i128;
+        let nanoseconds = self.total_months() as i128 * MONTH_NANOSECONDS
+            + (self.months_f * MONTH_NANOSECONDS as f64) as i128
+            + fixed * 1_000_000_000
+            + self.nanoseconds as i128;
+        nanoseconds.signum() as i8
+    }
+
+    /// Convert whole months worth of days into months, using the actual month lengths following a reference date
+    ///
+    /// Walks month by month from the date the relative days are added to, i.e. the reference with the constant
+    /// parameters and the years and months already held applied, in the direction of the days, moving days into
+    /// months as long as they cover a full month. A month is only moved when adding the result to the reference still
+    /// lands on the same date as adding self, so e.g. a month and 30 days from January 31st 2020, reaching March 30th
+    /// through the clamped February 29th, is left as is. Also left as is where the addition fails, see
+    /// `checked_add_to`.
+    pub fn normalize_days_into_months<Tz: chrono::TimeZone>(
+        &self,
+        reference: &chrono::DateTime<Tz>,
+    ) -> RelativeDelta {
+        let target = match self.checked_add_to(reference) {
+            Some(target) => target,
+            None => return *self,
+        };
+        // The date before the relative days, with the months moved so far
+        let before_days = |months: i64| {
+            RelativeDelta {
+                months: self.months + months,
+                days: 0,
+                hours: 0,
+                minutes: 0,
+                seconds: 0,
+                nanoseconds: 0,
+                iso_week: None,
+                weekday: None,
+                day_shift: None,
+                ..*self
+            }
+            .checked_add_to(reference)
+            .map(|dt| dt.date_naive())
+        };
+        let start = match before_days(0) {
+            Some(start) => start,
+            None => return *self,
+        };
+        let step = self.days.signum();
+        let mut ddt = *self;
+        let mut months = 0;
+        while ddt.days != 0 {
+            let next = match before_days(months + step) {
+                Some(next) => next,
+                None => break,
+            };
+            let days = self.days - (next - start).num_days();
+            if days.signum() == -step {
+                break;
+            }
+            let candidate = RelativeDelta {
+                months: self.months + months + step,
+                days,
+                ..*self
+            };
+            if candidate.checked_add_to(reference).as_ref() != Some(&target) {
+                break;
+            }
+            months += step;
+            ddt = candidate;
+        }
+        Builder::fix(&mut ddt, self.keeps_months());
+        ddt
+    }
+
+    /// Resolve the fractional months into days and time, using a reference date
+    ///
+    /// The fraction is taken of the length of the month reached by adding the delta without its fractional months to
+    /// the reference, constant year and month included, so e.g. half a month landing in April is 15 days. Whole days
+    /// are added to the relative days and the remainder to the time, leaving no fractional months. None if that
+    /// addition fails, see `checked_add_to`.
+    pub fn normalized<Tz: chrono::TimeZone>(
+        &self,
+        reference: &chrono::DateTime<Tz>,
+    ) -> Option<RelativeDelta> {
+        if self.months_f.is_zero() {
+            return Some(*self);
+        }
+        let landed = RelativeDelta {
+            months_f: 0.0,
+            ..*self
+        }
+        .checked_add_to(reference)?
+        .date_naive();
+        let days_f = self.months_f * num_days_in_month(landed.year(), landed.month()) as f64;
+        let fraction = Builder::normalize(0.0, 0.0, days_f, 0.0, 0.0, 0.0, 0);
+        let mut ddt = RelativeDelta {
+            months_f: 0.0,
+            days: self.days + fraction.days,
+            hours: self.hours + fraction.hours,
+            minutes: self.minutes + fraction.minutes,
+            seconds: self.seconds + fraction.seconds,
+            nanoseconds: self.nanoseconds + fraction.nanoseconds,
+            ..*self
+        };
+        Builder::fix(&mut ddt, self.keeps_months());
+        Some(ddt)
+    }
+
+    /// Whether adding self to two chrono::DateTime lands both in the same month of the same year
+    pub fn resolves_in_same_month<Tz: chrono::TimeZone>(
+        &self,
+        a: &chrono::DateTime<Tz>,
+        b: &chrono::DateTime<Tz>,
+    ) -> bool {
+        let (a, b) = (a + self, b + self);
+        (a.year(), a.month()) == (b.year(), b.month())
+    }
+
+    /// Count the points start, start + self, start + 2 * self, ... falling within start up to and including end
+    ///
+    /// Each point is computed from start rather than from the previous point, so e.g. monthly points from january 31st
+    /// stay on the last day of the month. Counting stops as soon as a point does not move past the previous one, so
+    /// empty or non advancing deltas count only start itself, or cannot be computed, e.g. past the bounds of chrono.
+    /// Returns 0 if end is before start.
+    pub fn occurrence_count_between<Tz: chrono::TimeZone>(
+        &self,
+        start: &chrono::DateTime<Tz>,
+        end: &chrono::DateTime<Tz>,
+    ) -> i64 {
+        if start > end {
+            return 0;
+        }
+        let mut count = 1;
+        let mut previous = start.clone();
+        loop {
+            let point = match self
+                .checked_mul(count)
+                .and_then(|rddt| rddt.checked_add_to(start))
+            {
+                Some(point) => point,
+                None => return count,
+            };
+            if point <= previous || point > *end {
+                return count;
+            }
+            count += 1;
+            previous = point;
+        }
+    }
+
+    /// Order two RelativeDelta by where they land when added to a reference chrono::DateTime
+    ///
+    /// Calendar deltas have no ordering by themselves, e.g. a month may be shorter or longer than 30 days depending on
+    /// the month it is added to. Comparing `reference + self` against `reference + other` gives a well defined ordering
+    /// for a given pivot, e.g. for sorting schedule offsets.
+    pub fn cmp_on<Tz: chrono::TimeZone>(
+        &self,
+        other: &RelativeDelta,
+        reference: &chrono::DateTime<Tz>,
+    ) -> std::cmp::Ordering {
+        (reference + self).cmp(&(reference + other))
+    }
+
+    /// Smallest and largest year a RelativeDelta can resolve to when added to a chrono::DateTime
+    ///
+    /// chrono bounds years well within `i32`, so the absolute year as well as the years resolved through relative
+    /// parameters should be validated against these rather than `i32`.
+    pub fn backend_year_bounds() -> (i64, i64) {
+        (
+            chrono::NaiveDate::MIN.year() as i64,
+            chrono::NaiveDate::MAX.year() as i64,
+        )
+    }
+
+    /// Reduce the relative years so the year resolved by adding self to a chrono::DateTime stays within
+    /// `backend_year_bounds`, e.g. to cap user input gracefully
+    ///
+    /// Deltas already resolving within the bounds are returned as is. Only years are clamped, so adding may still
+    /// overflow through the days and time, e.g. adding a day on the last day of the largest year.
+    pub fn clamp_for_backend<Tz: chrono::TimeZone>(
+        &self,
+        reference: &chrono::DateTime<Tz>,
+    ) -> RelativeDelta {
+        let (min, max) = Self::backend_year_bounds();
+        let year = resolved_year(self, reference);
+        let excess = if year > max {
+            year - max
+        } else if year < min {
+            year - min
+        } else {
+            return *self;
+        };
+        let years = (self.years as i64 - excess).clamp(i32::MIN as i64, i32::MAX as i64);
+        RelativeDelta {
+            years: years as i32,
+            ..*self
+        }
+    }
+
+    /// Weekday of the date resolved by adding self to a chrono::DateTime
+    pub fn resolved_weekday<Tz: chrono::TimeZone>(
+        &self,
+        dt: &chrono::DateTime<Tz>,
+    ) -> chrono::Weekday {
+        (dt + self).weekday()
+    }
+
+    /// Whether adding self to a chrono::DateTime leaves it unchanged
+    ///
+    /// A delta which cannot be added to `dt` is not considered an identity.
+    pub fn is_identity_on<Tz: chrono::TimeZone>(&self, dt: &chrono::DateTime<Tz>) -> bool {
+        self.checked_add_to(dt).as_ref() == Some(dt)
+    }
+
+    /// Whether subtracting self after adding it to a chrono::DateTime gets back to it, i.e. `(dt + self) - self == dt`
+    ///
+    /// Expected to hold for deltas holding nothing but relative days and time, and for deltas holding nothing but
+    /// relative years and months as long as the day is not clamped to the end of a month, e.g. one month from January
+    /// 31st lands on the last of February and comes back to the 28th or 29th. Mixing the two need not round trip, as
+    /// months are applied before days both ways. Constant parameters, a weekday and the other adjustments generally do
+    /// not round trip either, nor do deltas which cannot be added or subtracted, for which false is returned. As
+    /// subtracting adds the negation, this also audits that `(dt + self) + (-self) == dt`.
+    #[doc(alias = "is_reversible_on")]
+    pub fn verify_round_trip<Tz: chrono::TimeZone>(&self, dt: &chrono::DateTime<Tz>) -> bool {
+        self.checked_add_to(dt)
+            .and_then(|there| self.checked_neg()?.checked_add_to(&there))
+            .as_ref()
+            == Some(dt)
+    }
+
+    /// Add each of the deltas in order to a chrono::DateTime, each one to the result of the previous
+    ///
+    /// Unlike adding the sum of the deltas, every delta is applied in full against the running date, so constant
+    /// parameters and weekdays are kept and months are clamped at every step, e.g. a month and then the next Monday
+    /// from January 31st 2020 is March 2nd, while the next Monday and then a month is March 3rd.
+    pub fn compose_apply<Tz: chrono::TimeZone>(
+        dt: chrono::DateTime<Tz>,
+        deltas: &[RelativeDelta],
+    ) -> chrono::DateTime<Tz> {
+        deltas.iter().fold(dt, |acc, rd| acc + rd)
+    }
+
+    /// The first date on or after a chrono::DateTime falling on the weekday, keeping the time of day
+    ///
+    /// As with `with_weekday(weekday, 1)` the date itself counts, so the next friday from a friday is the same date.
+    /// Add a day first, or use `with_weekday(weekday, 2)` on a friday, to get the friday a week later.
+    pub fn next_weekday_from<Tz: chrono::TimeZone>(
+        dt: &chrono::DateTime<Tz>,
+        weekday: chrono::Weekday,
+    ) -> chrono::DateTime<Tz> {
+        dt.clone() + chrono::Duration::days(weekday_jump(dt.weekday(), weekday, 1))
+    }
+
+    /// The last date on or before a chrono::DateTime falling on the weekday, keeping the time of day
+    ///
+    /// As with `with_weekday(weekday, -1)` the date itself counts, so the previous friday from a friday is the same
+    /// date.
+    pub fn previous_weekday_from<Tz: chrono::TimeZone>(
+        dt: &chrono::DateTime<Tz>,
+        weekday: chrono::Weekday,
+    ) -> chrono::DateTime<Tz> {
+        dt.clone() + chrono::Duration::days(weekday_jump(dt.weekday(), weekday, -1))
+    }
+
+    /// Lazily yield `start` followed by self repeatedly added to it, e.g. for generating pay dates with `.take(n)`
+    ///
+    /// Each date is self added to the previous one rather than `start + n * self`, as month arithmetic is not linear,
+    /// so a month from January 31st 2020 gives February 29th, March 29th, April 29th and so on. The iterator is
+    /// infinite, unless adding fails, e.g. past the bounds of chrono, where it ends.
+    pub fn iter_from<Tz: chrono::TimeZone>(
+        &self,
+        start: chrono::DateTime<Tz>,
+    ) -> impl Iterator<Item = chrono::DateTime<Tz>> {
+        let delta = *self;
+        std::iter::successors(Some(start), move |dt| delta.checked_add_to(dt))
+    }
+
+    /// Add the time parameters to a chrono::NaiveTime, returning the wrapped time and the whole days it overflowed
+    ///
+    /// Constant hour, minute, second and nanosecond replace those of `t` first, after which the relative hours,
+    /// minutes, seconds and nanoseconds are added. Days are negative when wrapping back past midnight, and include the
+    /// relative days, as the builder carries hours over into days, e.g. 48 hours is 2 days. The other date parameters
+    /// are ignored.
+    pub fn add_time_with_overflow(&self, t: chrono::NaiveTime) -> (chrono::NaiveTime, i64) {
+        const DAY_NANOSECONDS: i128 = 86_400 * 1_000_000_000;
+        let base = (self.hour.unwrap_or_else(|| t.hour()) as i128 * 60
+            + self.minute.unwrap_or_else(|| t.minute()) as i128)
+            * 60
+            + self.second.unwrap_or_else(|| t.second()) as i128;
+        let relative = (self.hours as i128 * 60 + self.minutes as i128) * 60 + self.seconds as i128;
+        let total = (base + relative) * 1_000_000_000
+            + self.nanosecond.unwrap_or_else(|| t.nanosecond()) as i128
+            + self.nanoseconds as i128;
+        let nanoseconds = total.rem_euclid(DAY_NANOSECONDS);
+        let time = chrono::NaiveTime::from_num_seconds_from_midnight_opt(
+            (nanoseconds / 1_000_000_000) as u32,
+            (nanoseconds % 1_000_000_000) as u32,
+        )
+        .expect("time of day out of range");
+        (time, total.div_euclid(DAY_NANOSECONDS) as i64 + self.days)
+    }
+
+    /// Number of days the weekday would jump from a date falling on the given weekday
+    ///
+    /// Same computation as used when adding to e.g. a chrono::DateTime, where current is the weekday of the date after
+    /// all other parameters have been applied. None when no weekday is set.
+    pub fn weekday_jump_from(&self, current: chrono::Weekday) -> Option<i64> {
+        self.effective_weekday()
+            .map(|(weekday, nth)| weekday_jump(current, weekday, nth))
+    }
+
+    /// Add to a chrono::DateTime, scoping the weekday to the month it is resolved in
+    ///
+    /// Behaves like the `+` operator, but returns None if a positive nth weekday would jump past the end of the month,
+    /// e.g. when asking for the 5th monday in a month holding only four. The `+` operator instead overflows into the
+    /// following month. Like `checked_add_to`, None is returned as well where the operator would panic.
+    pub fn checked_add_weekday_in_month<Tz: chrono::TimeZone>(
+        &self,
+        dt: &chrono::DateTime<Tz>,
+    ) -> Option<chrono::DateTime<Tz>> {
+        let ret = RelativeDelta {
+            weekday: None,
+            day_shift: None,
+            ..*self
+        }
+        .checked_add_to(dt)?;
+        let jumped = match self.effective_weekday() {
+            Some((weekday, nth)) => {
+                let current = match self.weekday_anchor {
+                    Anchor::BeforeDays => {
+                        let before_days = RelativeDelta {
+                            days: 0,
+                            hours: 0,
+                            minutes: 0,
+                            seconds: 0,
+                            nanoseconds: 0,
+                            weekday: None,
+                            day_shift: None,
+                            iso_week: None,
+                            ..*self
+                        };
+                        before_days.checked_add_to(dt)?.weekday()
+                    }
+                    Anchor::AfterDays => ret.weekday(),
+                };
+                let jumpdays = weekday_jump(current, weekday, nth);
+                let jumped = add_days(ret.clone(), jumpdays).ok()?;
+                if nth > 0 && (jumped.year(), jumped.month()) != (ret.year(), ret.month()) {
+                    return None;
+                }
+                jumped
+            }
+            None => ret,
+        };
+        match self.day_shift {
+            Some(shift) => {
+                let shiftdays = shift.jump(jumped.weekday());
+                add_days(jumped, shiftdays).ok()
+            }
+            None => Some(jumped),
+        }
+    }
+}
+
+/// Lazily yield the dates from `start` up to but not including `end`, adding `step` to the previous date each time
+///
+/// A step moving backwards, e.g. `with_months(-1)`, yields dates from a later `start` down to but not including an
+/// earlier `end`. The direction is taken from the first step, and the range ends as soon as a step fails to move
+/// further in that direction rather than looping forever, e.g. an empty step yields no dates at all, and a constant
+/// month stops once reached. Like `RelativeDelta::iter_from`, each step is added to the previous date.
+pub fn range<Tz: chrono::TimeZone>(
+    start: chrono::DateTime<Tz>,
+    end: chrono::DateTime<Tz>,
+    step: RelativeDelta,
+) -> impl Iterator<Item = chrono::DateTime<Tz>> {
+    use std::cmp::Ordering;
+
+    let direction = step
+        .checked_add_to(&start)
+        .map_or(Ordering::Equal, |next| next.cmp(&start));
+    std::iter::successors(Some(start), move |dt| {
+        step.checked_add_to(dt)
+            .filter(|next| next.cmp(dt) == direction)
+    })
+    .take_while(move |dt| match direction {
+        Ordering::Greater => *dt < end,
+        Ordering::Less => *dt > end,
+        Ordering::Equal => false,
+    })
+}
+
+// Zero for months out of range. Probes the last days directly, as the month after december of the last year chrono
+// supports is out of range.
+pub fn num_days_in_month(year: i32, month: u32) -> u32 {
+    (28..=31)
+        .rev()
+        .find(|&day| chrono::NaiveDate::from_ymd_opt(year, month, day).is_some())
+        .unwrap_or(0)
+}
+
+// Days to jump from a date falling on `current` to reach the nth `weekday`, counting the date itself as an occurrence
+fn weekday_jump(current: chrono::Weekday, weekday: chrono::Weekday, nth: i64) -> i64 {
+    let mut jumpdays = (nth.abs() - 1) * 7;
+    if nth > 0 {
+        // Modulo 7 as in dateutil, so a date already on the weekday does not jump a week ahead
+        jumpdays +=
+            ((7 - current.num_days_from_monday() + weekday.num_days_from_monday()) % 7) as i64;
+    } else {
+        jumpdays += (current.num_days_from_monday() as i64 - weekday.num_days_from_monday() as i64)
+            .rem_euclid(7);
+        jumpdays *= -1;
+    }
+    jumpdays
+}
+
+// Days to jump from a date to the weekday in the ISO week of its year, clamping the week to the weeks in that year
+// None if the week is 0 or the date falls out of range
+fn iso_week_jump(date: chrono::NaiveDate, weekday: chrono::Weekday, week: u8) -> Option<i64> {
+    // December 28th always falls in the last ISO week of the year
+    let weeks = chrono::NaiveDate::from_ymd_opt(date.year(), 12, 28)?
+        .iso_week()
+        .week();
+    let target =
+        chrono::NaiveDate::from_isoywd_opt(date.year(), (week as u32).min(weeks), weekday)?;
+    Some((target - date).num_days())
+}
+
+// Convert a time::PrimitiveDateTime into a chrono::DateTime in UTC
+#[cfg(feature = "time")]
+fn primitive_to_chrono(dt: &time::PrimitiveDateTime) -> Option<chrono::DateTime<chrono::Utc>> {
+    use chrono::TimeZone;
+
+    let naive = chrono::NaiveDate::from_ymd_opt(dt.year(), dt.month() as u32, dt.day() as u32)?
+        .and_hms_nano_opt(
+            dt.hour() as u32,
+            dt.minute() as u32,
+            dt.second() as u32,
+            dt.nanosecond(),
+        )?;
+    Some(chrono::Utc.from_utc_datetime(&naive))
+}
+
+// Convert a chrono::DateTime back into a time::PrimitiveDateTime, dropping the timezone
+#[cfg(feature = "time")]
+fn chrono_to_primitive<Tz: chrono::TimeZone>(
+    dt: &chrono::DateTime<Tz>,
+) -> Option<time::PrimitiveDateTime> {
+    let month = time::Month::try_from(dt.month() as u8).ok()?;
+    time::Date::from_calendar_date(dt.year(), month, dt.day() as u8)
+        .ok()?
+        .with_hms_nano(
+            dt.hour() as u8,
+            dt.minute() as u8,
+            dt.second() as u8,
+            dt.nanosecond(),
+        )
+        .ok()
+}
+
+// Split fractional seconds into whole seconds and nanoseconds, both carrying the sign of the input
+fn split_seconds_f(seconds: f64) -> (i64, i64) {
+    let whole = seconds.trunc();
+    let nanoseconds = ((seconds - whole) * 1_000_000_000_f64).round();
+    (whole as i64, nanoseconds as i64)
+}
+
+// Total months between two (year, month) pairs, guarded against i64 overflow
+fn checked_months_diff(
+    later_year: i64,
+    later_month: i64,
+    earlier_year: i64,
+    earlier_month: i64,
+) -> Option<i64> {
+    later_year
+        .checked_sub(earlier_year)?
+        .checked_mul(12)?
+        .checked_add(later_month.checked_sub(earlier_month)?)
+}
+
+// Shift a DateTime by a number of months, clamping the day to the length of the resulting month
+fn checked_add_months<Tz: chrono::TimeZone>(
+    dt: &chrono::DateTime<Tz>,
+    months: i64,
+) -> Option<chrono::DateTime<Tz>> {
+    let total = (dt.year() as i64)
+        .checked_mul(12)?
+        .checked_add(dt.month0() as i64)?
+        .checked_add(months)?;
+    let (year, month0) = total.div_mod_floor(&12);
+    let year = i32::try_from(year).ok()?;
+    let month = month0 as u32 + 1;
+    let day = num_days_in_month(year, month).min(dt.day());
+    dt.timezone()
+        .with_ymd_and_hms(year, month, day, dt.hour(), dt.minute(), dt.second())
+        .single()
+        .and_then(|d| d.with_nanosecond(dt.nanosecond()))
+}
+
+// Length of the average Gregorian month of 365.2425 / 12 days
+const MONTH_NANOSECONDS: i128 = 2_629_746 * 1_000_000_000;
+
+/// Orders deltas by length, taking a month as the average Gregorian month of 30.436875 days
+///
+/// Only deltas holding nothing but whole relative parameters are ordered, as e.g. a constant day lands before or after
+/// a relative one depending on the date it is added to. Deltas with fractional months, constant parameters, a weekday
+/// or any of the other adjustments compare as None. So do differing deltas of the same length, e.g. `with_days(1)
+/// .and_hours(-1)` and `with_hours(23)`, keeping the ordering consistent with equality. There is no `Ord`, as
+/// RelativeDelta is not `Eq`; see `ByTotalMonths` for a total order on months.
+impl PartialOrd for RelativeDelta {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        let length = |rddt: &RelativeDelta| {
+            if rddt.months_f != 0.0 || rddt.relative_only() != *rddt {
+                return None;
+            }
+            let fixed = RelativeDelta {
+                years: 0,
+                months: 0,
+                ..*rddt
+            };
+            Some(rddt.total_months() as i128 * MONTH_NANOSECONDS + fixed.total_nanoseconds()?)
+        };
+        match length(self)?.cmp(&length(other)?) {
+            std::cmp::Ordering::Equal if self != other => None,
+            ordering => Some(ordering),
+        }
+    }
+}
+
+/// Renders the relative parameters as an ISO 8601 duration, e.g. `P1Y3M12DT5H30M45S`
+///
+/// Zero components are omitted, and the empty delta is `PT0S`. If no component is positive and at least one is
+/// negative, the whole duration is prefixed with `-`, otherwise components carry their own sign. Absolute parameters
+/// and the weekday follow in brackets, e.g. `P1M[day=1, weekday=Mon(1)]`.
+impl std::fmt::Display for RelativeDelta {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let months = self.months as f64 + self.months_f;
+        let nanos = self.seconds as i128 * 1_000_000_000 + self.nanoseconds as i128;
+        let signs = self.relative_signs();
+        let negative = signs.iter().all(|&v| v <= 0) && signs.contains(&-1);
+        let s = if negative { -1 } else { 1 };
+
+        if negative {
+            write!(f, "-")?;
+        }
+        write!(f, "P")?;
+        if self.years != 0 {
+            write!(f, "{}Y", self.years as i64 * s)?;
+        }
+        if months != 0.0 {
+            write!(f, "{}M", months * s as f64)?;
+        }
+        if self.days != 0 {
+            write!(f, "{}D", self.days * s)?;
+        }
+        if self.hours != 0 || self.minutes != 0 || nanos != 0 {
+            write!(f, "T")?;
+            if self.hours != 0 {
+                write!(f, "{}H", self.hours * s)?;
+            }
+            if self.minutes != 0 {
+                write!(f, "{}M", self.minutes * s)?;
+            }
+            if nanos != 0 {
+                let nanos = nanos * s as i128;
+                let sign = if nanos < 0 { "-" } else { "" };
+                let (whole, mut frac) = (nanos.abs() / 1_000_000_000, nanos.abs() % 1_000_000_000);
+                if frac == 0 {
+                    write!(f, "{}{}S", sign, whole)?;
+                } else {
+                    let mut width = 9;
+                    while frac % 10 == 0 {
+                        frac /= 10;
+                        width -= 1;
+                    }
+                    write!(f, "{}{}.{:0width$}S", sign, whole, frac, width = width)?;
+                }
+            }
+        } else if self.years == 0 && months == 0.0 && self.days == 0 {
+            write!(f, "T0S")?;
+        }
+
+        let mut sep = "[";
+        let mut field = |f: &mut std::fmt::Formatter<'_>, args: std::fmt::Arguments| {
+            let res = write!(f, "{}{}", sep, args);
+            sep = ", ";
+            res
+        };
+        if let Some(year) = self.year {
+            field(f, format_args!("year={}", year))?;
+        }
+        if let Some(month) = self.month {
+            field(f, format_args!("month={}", month))?;
+        }
+        if let Some(day) = self.day {
+            field(f, format_args!("day={}", day))?;
+        }
+        if let Some(hour) = self.hour {
+            field(f, format_args!("hour={}", hour))?;
+        }
+        if let Some(minute) = self.minute {
+            field(f, format_args!("minute={}", minute))?;
+        }
+        if let Some(second) = self.second {
+            field(f, format_args!("second={}", second))?;
+        }
+        if let Some(nanosecond) = self.nanosecond {
+            field(f, format_args!("nanosecond={}", nanosecond))?;
+        }
+        if let Some((weekday, nth)) = self.weekday {
+            field(f, format_args!("weekday={}({})", weekday, nth))?;
+        }
+        if self.week_start != WeekStart::default() {
+            field(f, format_args!("week_start={}", self.week_start.0))?;
+        }
+        if let Some((weekday, week)) = self.iso_week {
+            field(f, format_args!("iso_week={}({})", weekday, week))?;
+        }
+        if let Some(day_shift) = self.day_shift {
+            field(f, format_args!("day_shift={:?}", day_shift))?;
+        }
+        if self.pin_day {
+            field(f, format_args!("weekday_overrides_day=false"))?;
+        }
+        if self.weekday_anchor != Anchor::default() {
+            field(
+                f,
+                format_args!("weekday_relative_to={:?}", self.weekday_anchor),
+            )?;
+        }
+        if self.day_overflow != DayOverflow::default() {
+            field(f, format_args!("day_overflow={:?}", self.day_overflow))?;
+        }
+        if sep == ", " {
+            write!(f, "]")?;
+        }
+        Ok(())
     }
 }
 
-pub fn num_days_in_month(year: i32, month: u32) -> u32 {
-    chrono::NaiveDate::from_ymd_opt(year, month, 1)
-        .and_then(|d| {
-            d.clone()
-                .checked_add_months(Months::new(1))
-                .map(|nm| nm.signed_duration_since(d).num_days() as u32)
-        })
-        .unwrap_or(0)
+/// Parses an ISO 8601 duration, e.g. `P1Y2M10DT2H30M` or `P3W`
+///
+/// A leading `-` negates the whole duration, while components may also carry their own sign. Fractions are written with
+/// `.` or `,`. Bracketed parameters as written by `Display` are parsed as well, so `to_string().parse()` reproduces the
+/// delta.
+///
+/// ```edition2018
+/// # use relativedelta::RelativeDelta;
+/// let rddt: RelativeDelta = "P1Y2M10DT2H30M".parse().unwrap();
+/// assert_eq!(rddt, RelativeDelta::with_years(1).and_months(2).and_days(10).and_hours(2).and_minutes(30).new());
+/// ```
+impl std::str::FromStr for RelativeDelta {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (sign, s) = match s.strip_prefix('-') {
+            Some(s) => (-1, s),
+            None => (1, s),
+        };
+        let s = s.strip_prefix('P').ok_or(ParseError::MissingPeriod)?;
+        let (mut rest, params) = match s.find('[') {
+            Some(i) => (&s[..i], Some(&s[i..])),
+            None => (s, None),
+        };
+
+        // Years, months, weeks, days, hours, minutes and seconds
+        let mut whole = [0_i64; 7];
+        let mut frac = [0_f64; 7];
+        let mut nanoseconds = 0_i64;
+        let mut next = 0;
+        let mut in_time = false;
+        let mut found = false;
+        while !rest.is_empty() {
+            if let Some(r) = rest.strip_prefix('T') {
+                if in_time {
+                    return Err(ParseError::UnexpectedDesignator('T'));
+                }
+                in_time = true;
+                found = false;
+                next = 4;
+                rest = r;
+                continue;
+            }
+            let end = rest
+                .find(|c: char| c.is_ascii_alphabetic())
+                .ok_or_else(|| ParseError::MissingDesignator(rest.to_string()))?;
+            let (num, designator) = (&rest[..end], rest[end..].chars().next().unwrap());
+            let slot = match (in_time, designator) {
+                (false, 'Y') => 0,
+                (false, 'M') => 1,
+                (false, 'W') => 2,
+                (false, 'D') => 3,
+                (true, 'H') => 4,
+                (true, 'M') => 5,
+                (true, 'S') => 6,
+                (_, c) => return Err(ParseError::UnexpectedDesignator(c)),
+            };
+            if slot < next {
+                return Err(ParseError::UnexpectedDesignator(designator));
+            }
+            let invalid = || ParseError::InvalidNumber(num.to_string());
+            let (int, fraction) = match num.find(['.', ',']) {
+                Some(i) => (&num[..i], Some(&num[i + 1..])),
+                None => (num, None),
+            };
+            whole[slot] = int.parse().map_err(|_| invalid())?;
+            if let Some(digits) = fraction {
+                if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+                    return Err(invalid());
+                }
+                let s = if int.starts_with('-') { -1 } else { 1 };
+                if slot == 6 {
+                    let digits = format!("{:0<9}", &digits[..digits.len().min(9)]);
+                    nanoseconds = s * digits.parse::<i64>().map_err(|_| invalid())?;
+                } else {
+                    frac[slot] = s as f64
+                        * format!("0.{}", digits)
+                            .parse::<f64>()
+                            .map_err(|_| invalid())?;
+                }
+            }
+            next = slot + 1;
+            found = true;
+            rest = &rest[end + 1..];
+        }
+        if !found {
+            return Err(ParseError::Empty);
+        }
+
+        for v in whole.iter_mut() {
+            *v = v.checked_mul(sign).ok_or(ParseError::Overflow)?;
+        }
+        for v in frac.iter_mut() {
+            *v *= sign as f64;
+        }
+        nanoseconds *= sign;
+        let f = Builder::normalize(
+            frac[0],
+            frac[1],
+            frac[2] * 7.0 + frac[3],
+            frac[4],
+            frac[5],
+            0.0,
+            0,
+        );
+        let add = |a: i64, b: i64| a.checked_add(b).ok_or(ParseError::Overflow);
+        let mut delta = RelativeDelta {
+            years: i32::try_from(whole[0])
+                .ok()
+                .and_then(|v| v.checked_add(f.years))
+                .ok_or(ParseError::Overflow)?,
+            months: add(whole[1], f.months)?,
+            months_f: f.months_f,
+            days: add(
+                whole[2].checked_mul(7).ok_or(ParseError::Overflow)?,
+                add(whole[3], f.days)?,
+            )?,
+            hours: add(whole[4], f.hours)?,
+            minutes: add(whole[5], f.minutes)?,
+            seconds: add(whole[6], f.seconds)?,
+            nanoseconds: add(nanoseconds, f.nanoseconds)?,
+            ..Default::default()
+        };
+
+        if let Some(params) = params {
+            let inner = params
+                .strip_prefix('[')
+                .and_then(|p| p.strip_suffix(']'))
+                .ok_or_else(|| ParseError::InvalidParameter(params.to_string()))?;
+            for param in inner.split(',').map(str::trim) {
+                parse_parameter(&mut delta, param)
+                    .ok_or_else(|| ParseError::InvalidParameter(param.to_string()))?;
+            }
+            if let Some(err) = Builder::out_of_range(&delta) {
+                return Err(ParseError::InvalidParameter(err.to_string()));
+            }
+        }
+        Ok(delta.refixed(false))
+    }
+}
+
+/// Parses an ISO 8601 duration, same as `str::parse`
+impl TryFrom<&str> for RelativeDelta {
+    type Error = ParseError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+// Set a single `name=value` parameter as written by Display, None if it could not be parsed
+fn parse_parameter(delta: &mut RelativeDelta, param: &str) -> Option<()> {
+    fn weekday_nth<T: std::str::FromStr>(value: &str) -> Option<(chrono::Weekday, T)> {
+        let (weekday, n) = value.strip_suffix(')')?.split_once('(')?;
+        Some((weekday.parse().ok()?, n.parse().ok()?))
+    }
+
+    let (name, value) = param.split_once('=')?;
+    match name {
+        "year" => delta.year = Some(value.parse().ok()?),
+        "month" => delta.month = Some(value.parse().ok()?),
+        "day" => delta.day = Some(value.parse().ok()?),
+        "hour" => delta.hour = Some(value.parse().ok()?),
+        "minute" => delta.minute = Some(value.parse().ok()?),
+        "second" => delta.second = Some(value.parse().ok()?),
+        "nanosecond" => delta.nanosecond = Some(value.parse().ok()?),
+        "weekday" => delta.weekday = Some(weekday_nth(value)?),
+        "week_start" => delta.week_start = WeekStart(value.parse().ok()?),
+        "iso_week" => delta.iso_week = Some(weekday_nth(value)?),
+        "day_shift" => {
+            delta.day_shift = Some(match value {
+                "NextWeekday" => DayShift::NextWeekday,
+                "PreviousWeekday" => DayShift::PreviousWeekday,
+                "NextWeekendDay" => DayShift::NextWeekendDay,
+                _ => return None,
+            })
+        }
+        "weekday_overrides_day" => delta.pin_day = !value.parse::<bool>().ok()?,
+        "weekday_relative_to" => {
+            delta.weekday_anchor = match value {
+                "BeforeDays" => Anchor::BeforeDays,
+                "AfterDays" => Anchor::AfterDays,
+                _ => return None,
+            }
+        }
+        "day_overflow" => {
+            delta.day_overflow = match value {
+                "Clamp" => DayOverflow::Clamp,
+                "Rollover" => DayOverflow::Rollover,
+                "Error" => DayOverflow::Error,
+                _ => return None,
+            }
+        }
+        _ => return None,
+    }
+    Some(())
 }
 
+// Wrapping on overflow, see RelativeDelta::checked_neg
 impl_op_ex!(-|rhs: &RelativeDelta| -> RelativeDelta {
     RelativeDelta {
-        years: -rhs.years,
-        months: -rhs.months,
-        days: -rhs.days,
-        hours: -rhs.hours,
-        minutes: -rhs.minutes,
-        seconds: -rhs.seconds,
-        nanoseconds: -rhs.nanoseconds,
+        years: rhs.years.wrapping_neg(),
+        months: rhs.months.wrapping_neg(),
+        months_f: -rhs.months_f,
+        days: rhs.days.wrapping_neg(),
+        hours: rhs.hours.wrapping_neg(),
+        minutes: rhs.minutes.wrapping_neg(),
+        seconds: rhs.seconds.wrapping_neg(),
+        nanoseconds: rhs.nanoseconds.wrapping_neg(),
         ..*rhs
     }
 });
@@ -959,62 +4013,178 @@ impl_op_ex!(+ |lhs: &RelativeDelta, rhs: &RelativeDelta| -> RelativeDelta {
 
 impl_op_ex!(-|lhs: &RelativeDelta, rhs: &RelativeDelta| -> RelativeDelta { -rhs + lhs });
 
+// Summing folds with `+` from the empty delta, so only the relative parameters are summed
+impl std::iter::Sum<RelativeDelta> for RelativeDelta {
+    fn sum<I: Iterator<Item = RelativeDelta>>(iter: I) -> Self {
+        iter.fold(RelativeDelta::default(), |acc, rddt| acc + rddt)
+    }
+}
+
+impl<'a> std::iter::Sum<&'a RelativeDelta> for RelativeDelta {
+    fn sum<I: Iterator<Item = &'a RelativeDelta>>(iter: I) -> Self {
+        iter.fold(RelativeDelta::default(), |acc, rddt| acc + rddt)
+    }
+}
+
+// Assigning variants of the above, so like `+` and `-` these drop the constant parameters and weekday of both sides
+impl ops::AddAssign<&RelativeDelta> for RelativeDelta {
+    fn add_assign(&mut self, rhs: &RelativeDelta) {
+        *self = Add::add(&*self, rhs);
+    }
+}
+
+impl ops::AddAssign<RelativeDelta> for RelativeDelta {
+    fn add_assign(&mut self, rhs: RelativeDelta) {
+        *self += &rhs;
+    }
+}
+
+impl ops::SubAssign<&RelativeDelta> for RelativeDelta {
+    fn sub_assign(&mut self, rhs: &RelativeDelta) {
+        *self = ops::Sub::sub(&*self, rhs);
+    }
+}
+
+impl ops::SubAssign<RelativeDelta> for RelativeDelta {
+    fn sub_assign(&mut self, rhs: RelativeDelta) {
+        *self -= &rhs;
+    }
+}
+
+// Year resolved when adding to a DateTime, without any bounds on the result
+fn resolved_year<Tz: chrono::TimeZone>(lhs: &RelativeDelta, rhs: &chrono::DateTime<Tz>) -> i64 {
+    let month = lhs.month.unwrap_or(rhs.month()) as i64 - 1 + lhs.months;
+    lhs.year.unwrap_or(rhs.year()) as i64 + lhs.years as i64 + month.div_mod_floor(&12).0
+}
+
+// Reason adding to a DateTime failed
+enum AddError {
+    // The result falls past the bounds of chrono, after them if true
+    OutOfRange(bool),
+    // The resolved local date or time does not exist, e.g. in a DST gap or a day past the end of the month with
+    // DayOverflow::Error
+    Invalid,
+}
+
+// Add a duration which may not be representable, failing as out of range in the given direction
+fn add_signed<Tz: chrono::TimeZone>(
+    dt: chrono::DateTime<Tz>,
+    duration: Option<chrono::Duration>,
+    forward: bool,
+) -> Result<chrono::DateTime<Tz>, AddError> {
+    duration
+        .and_then(|d| dt.checked_add_signed(d))
+        .ok_or(AddError::OutOfRange(forward))
+}
+
+// Add days, failing as out of range in their direction
+fn add_days<Tz: chrono::TimeZone>(
+    dt: chrono::DateTime<Tz>,
+    days: i64,
+) -> Result<chrono::DateTime<Tz>, AddError> {
+    add_signed(dt, chrono::Duration::try_days(days), days >= 0)
+}
+
+// Add to a DateTime returning None where the operator would panic or overflow
+fn checked_add<Tz: chrono::TimeZone>(
+    lhs: &RelativeDelta,
+    rhs: &chrono::DateTime<Tz>,
+) -> Option<chrono::DateTime<Tz>> {
+    try_add(lhs, rhs).ok()
+}
+
+// Add to a DateTime telling out of range results apart from invalid local dates and times
+fn try_add<Tz: chrono::TimeZone>(
+    lhs: &RelativeDelta,
+    rhs: &chrono::DateTime<Tz>,
+) -> Result<chrono::DateTime<Tz>, AddError> {
+    let resolved = resolved_year(lhs, rhs);
+    let (min, max) = RelativeDelta::backend_year_bounds();
+    if !(min..=max).contains(&resolved) {
+        return Err(AddError::OutOfRange(resolved > rhs.year() as i64));
+    }
+    let year = resolved as i32;
+    let month =
+        (lhs.month.unwrap_or(rhs.month()) as i64 - 1 + lhs.months).mod_floor(&12) as u32 + 1;
+    // Clamp day to max number of days in calculated month, carrying the rest over if rolling over
+    let last = num_days_in_month(year, month);
+    let day = lhs.day.unwrap_or(rhs.day());
+    let rollover = match lhs.day_overflow {
+        _ if day <= last => 0,
+        DayOverflow::Clamp => 0,
+        DayOverflow::Rollover => day - last,
+        DayOverflow::Error => return Err(AddError::Invalid),
+    };
+    let datetime = rhs
+        .timezone()
+        .with_ymd_and_hms(
+            year,
+            month,
+            day.min(last),
+            lhs.hour.unwrap_or(rhs.hour()),
+            lhs.minute.unwrap_or(rhs.minute()),
+            lhs.second.unwrap_or(rhs.second()),
+        )
+        .single()
+        .and_then(|d| d.with_nanosecond(lhs.nanosecond.unwrap_or(rhs.nanosecond())))
+        .ok_or(AddError::Invalid)?;
+    let datetime = add_days(datetime, rollover as i64)?;
+    let before_days = datetime.weekday();
+
+    let ret = add_days(datetime, lhs.days)?;
+    let ret = add_signed(ret, chrono::Duration::try_hours(lhs.hours), lhs.hours >= 0)?;
+    let ret = add_signed(
+        ret,
+        chrono::Duration::try_minutes(lhs.minutes),
+        lhs.minutes >= 0,
+    )?;
+    let ret = add_signed(
+        ret,
+        chrono::Duration::try_seconds(lhs.seconds),
+        lhs.seconds >= 0,
+    )?;
+    let ret = add_signed(
+        ret,
+        Some(chrono::Duration::nanoseconds(lhs.nanoseconds)),
+        lhs.nanoseconds >= 0,
+    )?;
+
+    let ret = match lhs.iso_week {
+        Some((weekday, week)) => {
+            let jumpdays =
+                iso_week_jump(ret.date_naive(), weekday, week).ok_or(AddError::Invalid)?;
+            add_days(ret, jumpdays)?
+        }
+        None => ret,
+    };
+
+    let ret = match lhs.effective_weekday() {
+        Some((weekday, nth)) => {
+            let current = match lhs.weekday_anchor {
+                Anchor::BeforeDays => before_days,
+                Anchor::AfterDays => ret.weekday(),
+            };
+            add_days(ret.clone(), weekday_jump(current, weekday, nth))?
+        }
+        None => ret,
+    };
+
+    match lhs.day_shift {
+        Some(shift) => {
+            let shiftdays = shift.jump(ret.weekday());
+            add_days(ret, shiftdays)
+        }
+        None => Ok(ret),
+    }
+}
+
 // Unfortunately we have to implement them manually as we dont want to restrict ourselves on a timezone
 impl<Tz: chrono::TimeZone> Add<&chrono::DateTime<Tz>> for &RelativeDelta {
     type Output = chrono::DateTime<Tz>;
 
     fn add(self, rhs: &chrono::DateTime<Tz>) -> Self::Output {
-        let mut year = self.year.unwrap_or(rhs.year()) + self.years;
-        let month = self.month.unwrap_or(rhs.month()) as i64 + self.months;
-        let (mut extra_years, mut relative_month) = month.div_rem(&12);
-        if relative_month <= 0 {
-            extra_years -= 1;
-            relative_month = 12 + relative_month;
-        }
-        assert!(
-            (1..=12).contains(&relative_month),
-            "relative month was {}",
-            relative_month
-        );
-        year += extra_years as i32;
-
-        let real_month = relative_month as u32;
-        // Clamp day to max number of days in calculated month
-        let day = num_days_in_month(year, real_month).min(self.day.unwrap_or(rhs.day()));
-        let hour = self.hour.unwrap_or(rhs.hour());
-        let minute = self.minute.unwrap_or(rhs.minute());
-        let second = self.second.unwrap_or(rhs.second());
-        let nanosecond = self.nanosecond.unwrap_or(rhs.nanosecond());
-
-        let datetime = rhs
-            .timezone()
-            .with_ymd_and_hms(year, real_month, day, hour, minute, second)
-            .single()
-            .and_then(|d| d.with_nanosecond(nanosecond))
-            .expect(&format!("Could not create DateTime from year: {year}, month: {real_month}, day: {day}, hour: {hour}, minute: {minute}, second: {second}, nano: {nanosecond}."));
-
-        let ret = datetime
-            + chrono::Duration::days(self.days)
-            + chrono::Duration::hours(self.hours)
-            + chrono::Duration::minutes(self.minutes)
-            + chrono::Duration::seconds(self.seconds)
-            + chrono::Duration::nanoseconds(self.nanoseconds);
-
-        if let Some((weekday, nth)) = self.weekday {
-            let mut jumpdays = (nth.abs() - 1) * 7;
-            if nth > 0 {
-                jumpdays += (7 - ret.weekday().num_days_from_monday()
-                    + weekday.num_days_from_monday()) as i64;
-            } else {
-                jumpdays += ((ret.weekday().num_days_from_monday()
-                    - weekday.num_days_from_monday())
-                    % 7) as i64;
-                jumpdays *= -1;
-            }
-            ret + chrono::Duration::days(jumpdays)
-        } else {
-            ret
-        }
+        self.checked_add_to(rhs)
+            .unwrap_or_else(|| panic!("Could not add {:?} to {:?}", self, rhs))
     }
 }
 
@@ -1192,10 +4362,252 @@ impl<Tz: chrono::TimeZone> ops::Sub<RelativeDelta> for chrono::DateTime<Tz> {
     }
 }
 
+/// Method form of adding and subtracting a RelativeDelta, for chaining on owned as well as borrowed chrono::DateTime
+///
+/// ```edition2018
+/// # use chrono::{TimeZone, Utc};
+/// # use relativedelta::{DateTimeExt, RelativeDelta};
+/// let dt = Utc.with_ymd_and_hms(2020, 1, 31, 0, 0, 0).unwrap();
+/// let later = dt.plus_delta(RelativeDelta::with_months(1).new()).minus_delta(RelativeDelta::with_days(1).new());
+/// assert_eq!(later, Utc.with_ymd_and_hms(2020, 2, 28, 0, 0, 0).unwrap());
+/// ```
+pub trait DateTimeExt {
+    type Output;
+
+    /// Same as `self + rd`
+    fn plus_delta(self, rd: RelativeDelta) -> Self::Output;
+
+    /// Same as `self - rd`
+    fn minus_delta(self, rd: RelativeDelta) -> Self::Output;
+}
+
+impl<Tz: chrono::TimeZone> DateTimeExt for chrono::DateTime<Tz> {
+    type Output = chrono::DateTime<Tz>;
+
+    fn plus_delta(self, rd: RelativeDelta) -> Self::Output {
+        self + rd
+    }
+
+    fn minus_delta(self, rd: RelativeDelta) -> Self::Output {
+        self - rd
+    }
+}
+
+impl<Tz: chrono::TimeZone> DateTimeExt for &chrono::DateTime<Tz> {
+    type Output = chrono::DateTime<Tz>;
+
+    fn plus_delta(self, rd: RelativeDelta) -> Self::Output {
+        self + rd
+    }
+
+    fn minus_delta(self, rd: RelativeDelta) -> Self::Output {
+        self - rd
+    }
+}
+
+// Add for chrono::NaiveDate
+//
+// Only the date parameters are applied. The relative and constant time parameters are ignored rather than carried over
+// into days, while the weekday, ISO week and day shift resolve as they would for a DateTime at midnight. Note that the
+// builder already folds whole days out of relative hours, so only the sub-day remainder is dropped: 23 hours leaves
+// the date as is, while 24 hours moves it to the next day.
+
+impl Add<&RelativeDelta> for chrono::NaiveDate {
+    type Output = chrono::NaiveDate;
+
+    fn add(self, rhs: &RelativeDelta) -> Self::Output {
+        let date_only = RelativeDelta {
+            hours: 0,
+            minutes: 0,
+            seconds: 0,
+            nanoseconds: 0,
+            hour: None,
+            minute: None,
+            second: None,
+            nanosecond: None,
+            ..*rhs
+        };
+        date_only
+            .checked_add_to(&self.and_time(chrono::NaiveTime::MIN).and_utc())
+            .map(|dt| dt.date_naive())
+            .unwrap_or_else(|| panic!("Could not add {:?} to {:?}", rhs, self))
+    }
+}
+
+impl Add<RelativeDelta> for chrono::NaiveDate {
+    type Output = chrono::NaiveDate;
+
+    fn add(self, rhs: RelativeDelta) -> Self::Output {
+        Add::add(self, &rhs)
+    }
+}
+
+impl Add<&Builder> for chrono::NaiveDate {
+    type Output = chrono::NaiveDate;
+
+    fn add(self, rhs: &Builder) -> Self::Output {
+        self + rhs.new()
+    }
+}
+
+impl Add<&mut Builder> for chrono::NaiveDate {
+    type Output = chrono::NaiveDate;
+
+    fn add(self, rhs: &mut Builder) -> Self::Output {
+        self + rhs.new()
+    }
+}
+
+impl Add<Builder> for chrono::NaiveDate {
+    type Output = chrono::NaiveDate;
+
+    fn add(self, rhs: Builder) -> Self::Output {
+        self + rhs.new()
+    }
+}
+
+// Add for chrono::NaiveTime, wrapping around midnight, see RelativeDelta::add_time_with_overflow
+
+impl Add<&RelativeDelta> for chrono::NaiveTime {
+    type Output = chrono::NaiveTime;
+
+    fn add(self, rhs: &RelativeDelta) -> Self::Output {
+        rhs.add_time_with_overflow(self).0
+    }
+}
+
+impl Add<RelativeDelta> for chrono::NaiveTime {
+    type Output = chrono::NaiveTime;
+
+    fn add(self, rhs: RelativeDelta) -> Self::Output {
+        Add::add(self, &rhs)
+    }
+}
+
+impl Add<&Builder> for chrono::NaiveTime {
+    type Output = chrono::NaiveTime;
+
+    fn add(self, rhs: &Builder) -> Self::Output {
+        self + rhs.new()
+    }
+}
+
+impl Add<&mut Builder> for chrono::NaiveTime {
+    type Output = chrono::NaiveTime;
+
+    fn add(self, rhs: &mut Builder) -> Self::Output {
+        self + rhs.new()
+    }
+}
+
+impl Add<Builder> for chrono::NaiveTime {
+    type Output = chrono::NaiveTime;
+
+    fn add(self, rhs: Builder) -> Self::Output {
+        self + rhs.new()
+    }
+}
+
+// Add and sub for hifitime::Epoch
+//
+// The calendar parameters are applied to the gregorian UTC representation of the epoch, while hours, minutes, seconds
+// and nanoseconds are added as a fixed hifitime::Duration afterwards, deferring leap seconds to hifitime. The
+// resulting epoch is in the UTC time scale, unless only fixed parameters are given.
+
+#[cfg(feature = "hifitime")]
+impl Add<hifitime::Epoch> for &RelativeDelta {
+    type Output = hifitime::Epoch;
+
+    fn add(self, rhs: hifitime::Epoch) -> Self::Output {
+        use chrono::TimeZone;
+
+        let fixed = ((self.hours as i128 * 60 + self.minutes as i128) * 60 + self.seconds as i128)
+            * 1_000_000_000
+            + self.nanoseconds as i128;
+        let calendar = RelativeDelta {
+            hours: 0,
+            minutes: 0,
+            seconds: 0,
+            nanoseconds: 0,
+            ..*self
+        };
+        if calendar.is_empty() {
+            return rhs + hifitime::Duration::from_total_nanoseconds(fixed);
+        }
+
+        let (year, month, day, hour, minute, second, nanos) = rhs.to_gregorian_utc();
+        // chrono represents a leap second as second 59 with an overflowing nanosecond
+        let (second, nanos) = if second == 60 {
+            (59, nanos + 1_000_000_000)
+        } else {
+            (second, nanos)
+        };
+        let naive = chrono::NaiveDate::from_ymd_opt(year, month as u32, day as u32)
+            .and_then(|d| d.and_hms_nano_opt(hour as u32, minute as u32, second as u32, nanos))
+            .expect("epoch out of range for calendar arithmetic");
+        let moved = chrono::Utc.from_utc_datetime(&naive) + calendar;
+        let epoch = hifitime::Epoch::from_gregorian_utc(
+            moved.year(),
+            moved.month() as u8,
+            moved.day() as u8,
+            moved.hour() as u8,
+            moved.minute() as u8,
+            moved.second() as u8,
+            moved.nanosecond().min(999_999_999),
+        );
+        epoch + hifitime::Duration::from_total_nanoseconds(fixed)
+    }
+}
+
+#[cfg(feature = "hifitime")]
+impl Add<hifitime::Epoch> for RelativeDelta {
+    type Output = hifitime::Epoch;
+
+    fn add(self, rhs: hifitime::Epoch) -> Self::Output {
+        Add::add(&self, rhs)
+    }
+}
+
+#[cfg(feature = "hifitime")]
+impl Add<&RelativeDelta> for hifitime::Epoch {
+    type Output = hifitime::Epoch;
+
+    fn add(self, rhs: &RelativeDelta) -> Self::Output {
+        rhs + self
+    }
+}
+
+#[cfg(feature = "hifitime")]
+impl Add<RelativeDelta> for hifitime::Epoch {
+    type Output = hifitime::Epoch;
+
+    fn add(self, rhs: RelativeDelta) -> Self::Output {
+        rhs + self
+    }
+}
+
+#[cfg(feature = "hifitime")]
+impl ops::Sub<&RelativeDelta> for hifitime::Epoch {
+    type Output = hifitime::Epoch;
+
+    fn sub(self, rhs: &RelativeDelta) -> Self::Output {
+        self + (-rhs)
+    }
+}
+
+#[cfg(feature = "hifitime")]
+impl ops::Sub<RelativeDelta> for hifitime::Epoch {
+    type Output = hifitime::Epoch;
+
+    fn sub(self, rhs: RelativeDelta) -> Self::Output {
+        self + (-rhs)
+    }
+}
+
 fn mul(lhs: &RelativeDelta, rhs: f64) -> RelativeDelta {
     // Calculate relatives
     let years = lhs.years as f64 * rhs;
-    let months = lhs.months as f64 * rhs;
+    let months = (lhs.months as f64 + lhs.months_f) * rhs;
     let days = lhs.days as f64 * rhs;
     let hours = lhs.hours as f64 * rhs;
     let minutes = lhs.minutes as f64 * rhs;
@@ -1210,7 +4622,8 @@ fn mul(lhs: &RelativeDelta, rhs: f64) -> RelativeDelta {
         seconds,
         nanoseconds as i64,
     );
-    // Copy over constants
+    // Copy over constants and the weekday, which are kept as is like in integer multiplication
+    rddt_mul.weekday = lhs.weekday;
     rddt_mul.year = lhs.year;
     rddt_mul.month = lhs.month;
     rddt_mul.day = lhs.day;
@@ -1218,11 +4631,23 @@ fn mul(lhs: &RelativeDelta, rhs: f64) -> RelativeDelta {
     rddt_mul.minute = lhs.minute;
     rddt_mul.second = lhs.second;
     rddt_mul.nanosecond = lhs.nanosecond;
+    rddt_mul.week_start = lhs.week_start;
+    rddt_mul.day_shift = lhs.day_shift;
+    rddt_mul.pin_day = lhs.pin_day;
+    rddt_mul.iso_week = lhs.iso_week;
+    rddt_mul.weekday_anchor = lhs.weekday_anchor;
+    rddt_mul.day_overflow = lhs.day_overflow;
     rddt_mul.new()
 }
 
 impl_op_ex_commutative!(*|lhs: &RelativeDelta, rhs: f64| -> RelativeDelta { mul(lhs, rhs) });
 
+// Integer multiplication stays exact, where going through f64 loses precision beyond 2^53, and panics on overflow
+impl_op_ex_commutative!(*|lhs: &RelativeDelta, rhs: i64| -> RelativeDelta {
+    lhs.checked_mul(rhs)
+        .unwrap_or_else(|| panic!("Could not multiply {:?} by {}", lhs, rhs))
+});
+
 /*
 impl_op_ex!(/ |lhs: &RelativeDelta, rhs: &RelativeDelta| -> f64 {
     let lhst = lhs.years as i64 * 360 + lhs.months * 30 + lhs.days.min(30);
@@ -1244,15 +4669,47 @@ impl_op_ex!(/ |lhs: &RelativeDelta, rhs: usize| -> RelativeDelta {
     lhs / (rhs as f64)
 });
 
-/*
+// Assigning variants of `*` and `/` with f64, keeping the constant parameters the same way
+impl ops::MulAssign<f64> for RelativeDelta {
+    fn mul_assign(&mut self, rhs: f64) {
+        *self = mul(self, rhs);
+    }
+}
+
+impl ops::DivAssign<f64> for RelativeDelta {
+    fn div_assign(&mut self, rhs: f64) {
+        *self = ops::Div::div(&*self, rhs);
+    }
+}
+
 impl TryFrom<RelativeDelta> for chrono::NaiveDateTime {
-    type Error = ();
+    type Error = FromError;
+
+    fn try_from(rddt: RelativeDelta) -> Result<Self, Self::Error> {
+        rddt.try_into_datetime_with_default_time((0, 0, 0, 0))
+    }
+}
 
-    fn try_from(value: RelativeDelta) -> Result<Self, Self::Error> {
-        todo!()
+/// Fixed length deltas, holding nothing but relative days, hours, minutes, seconds and nanoseconds, see
+/// `RelativeDelta::total_nanoseconds`
+impl TryFrom<RelativeDelta> for chrono::Duration {
+    type Error = FromError;
+
+    fn try_from(rddt: RelativeDelta) -> Result<Self, Self::Error> {
+        let nanoseconds = rddt
+            .total_nanoseconds()
+            .ok_or(FromError::NonExactDuration)?;
+        i64::try_from(nanoseconds.div_euclid(1_000_000_000))
+            .ok()
+            .and_then(chrono::Duration::try_seconds)
+            .and_then(|d| {
+                d.checked_add(&chrono::Duration::nanoseconds(
+                    nanoseconds.rem_euclid(1_000_000_000) as i64,
+                ))
+            })
+            .ok_or_else(|| FromError::InvalidComponent("duration out of range".to_string()))
     }
 }
- */
 
 impl From<RelativeDelta> for Option<chrono::NaiveDateTime> {
     fn from(rddt: RelativeDelta) -> Self {
@@ -1293,5 +4750,184 @@ mod tests {
         assert_eq!(num_days_in_month(2000, 10), 31);
         assert_eq!(num_days_in_month(2000, 11), 30);
         assert_eq!(num_days_in_month(2000, 12), 31);
+        assert_eq!(num_days_in_month(chrono::NaiveDate::MAX.year(), 12), 31);
+        assert_eq!(num_days_in_month(2000, 13), 0);
+    }
+
+    #[test]
+    fn test_to_iso8601_weeks() {
+        assert_eq!(
+            RelativeDelta::with_days(14).new().to_iso8601_weeks(),
+            Some("P2W".to_string())
+        );
+        assert_eq!(
+            RelativeDelta::with_days(-7).new().to_iso8601_weeks(),
+            Some("-P1W".to_string())
+        );
+        assert_eq!(RelativeDelta::with_days(15).new().to_iso8601_weeks(), None);
+        assert_eq!(
+            RelativeDelta::with_days(14)
+                .and_hours(1)
+                .new()
+                .to_iso8601_weeks(),
+            None
+        );
+        assert_eq!(RelativeDelta::default().to_iso8601_weeks(), None);
+    }
+
+    #[test]
+    fn test_integral_months_f_folds_into_months() {
+        let rd = RelativeDelta::with_months(2).and_months_f(1.0).new();
+        assert_eq!(rd.months, 3);
+        assert!(rd.months_f.is_zero());
+
+        let rd = RelativeDelta::with_months(2).and_months_f(0.5).new();
+        assert_eq!(rd.months, 2);
+        assert_eq!(rd.months_f, 0.5);
+    }
+
+    #[test]
+    fn test_checked_neg() {
+        let min_seconds = RelativeDelta {
+            seconds: i64::MIN,
+            ..Default::default()
+        };
+        assert_eq!(min_seconds.checked_neg(), None);
+        assert_eq!((-min_seconds).seconds, i64::MIN);
+        assert_eq!(
+            RelativeDelta::with_years(i32::MIN).new().checked_neg(),
+            None
+        );
+
+        let rd = RelativeDelta::with_years(1)
+            .and_months_f(0.5)
+            .and_days(-2)
+            .and_nanoseconds(3)
+            .and_day(Some(4))
+            .new();
+        let negated = rd.checked_neg().unwrap();
+        assert_eq!(negated.years, -1);
+        assert_eq!(negated.months_f, -0.5);
+        assert_eq!(negated.days, 2);
+        assert_eq!(negated.nanoseconds, -3);
+        assert_eq!(negated.day, Some(4));
+        assert_eq!(negated, -rd);
+    }
+
+    #[test]
+    fn test_try_into_datetime() {
+        let date = RelativeDelta::with_year(2020)
+            .and_month(Some(2))
+            .and_day(Some(29))
+            .new();
+        let noon = date.try_into_datetime_with_default_time((12, 0, 0, 0));
+        assert_eq!(
+            noon,
+            Ok(chrono::NaiveDate::from_ymd_opt(2020, 2, 29)
+                .unwrap()
+                .and_hms_opt(12, 0, 0)
+                .unwrap())
+        );
+        assert_eq!(
+            chrono::NaiveDateTime::try_from(date),
+            Ok(chrono::NaiveDate::from_ymd_opt(2020, 2, 29)
+                .unwrap()
+                .and_hms_opt(0, 0, 0)
+                .unwrap())
+        );
+
+        // Set absolutes take precedence over the default
+        let date = RelativeDelta::with_year(2020)
+            .and_month(Some(2))
+            .and_day(Some(29))
+            .and_minute(Some(45))
+            .new();
+        assert_eq!(
+            date.try_into_datetime_with_default_time((12, 0, 0, 0)),
+            Ok(chrono::NaiveDate::from_ymd_opt(2020, 2, 29)
+                .unwrap()
+                .and_hms_opt(12, 45, 0)
+                .unwrap())
+        );
+
+        let no_day = RelativeDelta::with_year(2020).and_month(Some(2)).new();
+        assert_eq!(
+            chrono::NaiveDateTime::try_from(no_day),
+            Err(FromError::MissingDateComponents)
+        );
+        let feb30 = RelativeDelta::with_year(2020)
+            .and_month(Some(2))
+            .and_day(Some(30))
+            .new();
+        assert_eq!(
+            chrono::NaiveDateTime::try_from(feb30),
+            Err(FromError::InvalidDateComponents)
+        );
+    }
+
+    #[test]
+    fn test_checked_into_naive_datetime() {
+        let valid = RelativeDelta::with_year(2020)
+            .and_month(Some(2))
+            .and_day(Some(29))
+            .and_hour(Some(13))
+            .new();
+        assert_eq!(
+            valid.checked_into_naive_datetime(),
+            Ok(chrono::NaiveDate::from_ymd_opt(2020, 2, 29)
+                .unwrap()
+                .and_hms_opt(13, 0, 0)
+                .unwrap())
+        );
+
+        let feb30 = RelativeDelta::with_year(2021)
+            .and_month(Some(2))
+            .and_day(Some(30))
+            .new();
+        assert_eq!(
+            feb30.checked_into_naive_datetime(),
+            Err(FromError::InvalidComponent(
+                "day 30 invalid for month 2 of 2021".to_string()
+            ))
+        );
+
+        // Out of range absolutes are rejected by the builder, parsing and deserialization, so only a struct literal
+        // like this one can hold them
+        let month0 = RelativeDelta {
+            year: Some(2021),
+            month: Some(0),
+            day: Some(1),
+            ..Default::default()
+        };
+        assert_eq!(
+            month0.checked_into_naive_datetime(),
+            Err(FromError::InvalidComponent(
+                "month 0 out of range 1..=12".to_string()
+            ))
+        );
+        let minute60 = RelativeDelta {
+            minute: Some(60),
+            ..valid
+        };
+        assert_eq!(
+            minute60.checked_into_naive_datetime(),
+            Err(FromError::InvalidComponent(
+                "minute 60 out of range 0..=59".to_string()
+            ))
+        );
+        assert_eq!(
+            RelativeDelta::with_year(2021)
+                .new()
+                .checked_into_naive_datetime(),
+            Err(FromError::MissingDateComponents)
+        );
+    }
+
+    #[test]
+    fn test_checked_months_diff() {
+        assert_eq!(checked_months_diff(2021, 3, 2020, 1), Some(14));
+        assert_eq!(checked_months_diff(2020, 1, 2021, 3), Some(-14));
+        assert_eq!(checked_months_diff(i64::MAX, 1, -1, 1), None);
+        assert_eq!(checked_months_diff(i64::MAX / 12, 12, 0, 1), None);
     }
 }